@@ -890,6 +890,20 @@ fn dot_f32_10e6(bench: &mut test::Bencher) {
     bench.iter(|| av.dot(&bv));
 }
 
+#[bench]
+fn mat_mul_f64_3x3(bench: &mut test::Bencher) {
+    let a = Array::<f64, _>::ones((3, 3));
+    let b = Array::<f64, _>::ones((3, 3));
+    bench.iter(|| a.dot(&b));
+}
+
+#[bench]
+fn mat_mul_f32_4x4(bench: &mut test::Bencher) {
+    let a = Array::<f32, _>::ones((4, 4));
+    let b = Array::<f32, _>::ones((4, 4));
+    bench.iter(|| a.dot(&b));
+}
+
 #[bench]
 fn dot_extended(bench: &mut test::Bencher) {
     let m = 10;
@@ -20,6 +20,16 @@ fn zeros_f64(bench: &mut Bencher) {
     bench.iter(|| Array::<f64, _>::zeros((128, 128)))
 }
 
+#[bench]
+fn from_elem_f64(bench: &mut Bencher) {
+    bench.iter(|| Array::<f64, _>::from_elem((128, 128), 1.))
+}
+
+#[bench]
+fn from_elem_u8_large(bench: &mut Bencher) {
+    bench.iter(|| Array::<u8, _>::from_elem((1000, 10_000), 1))
+}
+
 #[bench]
 fn map_regular(bench: &mut test::Bencher) {
     let a = Array::linspace(0., 127., 128).into_shape((8, 16)).unwrap();
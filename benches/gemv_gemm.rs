@@ -74,3 +74,22 @@ where
         general_mat_mul(Complex::one(), &a, &x, Complex::zero(), &mut y);
     });
 }
+
+#[bench]
+fn dot_blocked_128_i32_block16(bench: &mut Bencher) {
+    dot_blocked_bench::<i32>(128, 16, bench);
+}
+
+#[bench]
+fn dot_blocked_128_i32_block64(bench: &mut Bencher) {
+    dot_blocked_bench::<i32>(128, 64, bench);
+}
+
+fn dot_blocked_bench<A>(size: usize, block: usize, bench: &mut Bencher)
+where
+    A: LinalgScalar,
+{
+    let a = Array2::<A>::zeros((size, size));
+    let b = Array2::<A>::zeros((size, size));
+    bench.iter(|| a.dot_blocked(&b, block));
+}
@@ -5,10 +5,14 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use super::{ArrayBase, ArrayView, Axis, Data, Dimension, NdProducer};
-use crate::aliases::{Ix1, IxDyn};
+use super::{ArrayBase, ArrayView, Axis, Data, Dimension, Ix, NdProducer};
+use crate::aliases::{Ix1, Ix2, IxDyn};
+use std::cell::RefCell;
 use std::fmt;
+use std::fmt::Write as _;
 use alloc::format;
+use alloc::string::String;
+use alloc::vec;
 
 /// Default threshold, below this element count, we don't ellipsize
 const ARRAY_MANY_ELEMENT_LIMIT: usize = 500;
@@ -28,21 +32,115 @@ const AXIS_2D_OVERFLOW_LIMIT: usize = 22;
 /// The string used as an ellipsis.
 const ELLIPSIS: &str = "...";
 
+/// Options for formatting arrays with [`.display_with()`](ArrayBase::display_with).
+///
+/// The default options format arrays in the same way as their `Display` implementation.
+///
+/// ```
+/// use ndarray::{array, FormatOptions};
+///
+/// let a = array![[1.5, -20.25], [300., 4.125]];
+/// let opts = FormatOptions::new().decimal_align(true);
+/// assert_eq!(format!("{}", a.display_with(&opts)),
+///            "[[  1.5  , -20.25 ],\n \
+///               [300    ,   4.125]]");
+/// ```
 #[derive(Clone, Debug)]
-struct FormatOptions {
+pub struct FormatOptions {
     axis_collapse_limit: usize,
     axis_collapse_limit_next_last: usize,
     axis_collapse_limit_last: usize,
+    /// Whether the limits apply even to arrays with few elements
+    always_summarize: bool,
+    decimal_align: bool,
+    sign_space: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl FormatOptions {
-    pub(crate) fn default_for_array(nelem: usize, no_limit: bool) -> Self {
-        let default = Self {
+    /// Create the default formatting options.
+    pub fn new() -> Self {
+        FormatOptions {
             axis_collapse_limit: AXIS_LIMIT_STACKED,
             axis_collapse_limit_next_last: AXIS_LIMIT_COL,
             axis_collapse_limit_last: AXIS_LIMIT_ROW,
-        };
-        default.set_no_limit(no_limit || nelem < ARRAY_MANY_ELEMENT_LIMIT)
+            always_summarize: false,
+            decimal_align: false,
+            sign_space: false,
+        }
+    }
+
+    /// Print at most `limit` items along each axis.
+    ///
+    /// When an axis is longer than `limit`, only its first and last `limit / 2` items
+    /// (but at least the first item) are printed, separated by an ellipsis. Unlike
+    /// the default limits, which only apply to arrays with many elements, this limit
+    /// applies to arrays of any size (except when using the alternate flag `{:#}`).
+    ///
+    /// ```
+    /// use ndarray::{Array, FormatOptions};
+    ///
+    /// let a = Array::from_shape_fn(10, |i| i as f64);
+    /// let opts = FormatOptions::new().elements_limit(4);
+    /// assert_eq!(format!("{}", a.display_with(&opts)), "[0, 1, ..., 8, 9]");
+    /// ```
+    pub fn elements_limit(mut self, limit: usize) -> Self {
+        self.axis_collapse_limit = limit;
+        self.axis_collapse_limit_next_last = limit;
+        self.axis_collapse_limit_last = limit;
+        self.always_summarize = true;
+        self
+    }
+
+    /// Align the elements on their decimal point (off by default).
+    ///
+    /// Each printed element is padded with spaces on the left of its integer part and
+    /// on the right of its fractional part, so that the decimal points of all printed
+    /// elements line up, like NumPy does for float arrays. Elements without a decimal
+    /// point (like `NaN`, or integers) are aligned as if their decimal point was at
+    /// the end. The precision and `+` flags of the formatter are passed on to the
+    /// elements, and the width is applied to the aligned elements.
+    ///
+    /// Only the printed elements are measured, and nothing is buffered: each printed
+    /// element is formatted twice, once to measure it and once to write it, so the
+    /// memory use does not depend on the size of the array.
+    pub fn decimal_align(mut self, decimal_align: bool) -> Self {
+        self.decimal_align = decimal_align;
+        self
+    }
+
+    /// Reserve a leading space for the sign of non-negative elements (off by default).
+    ///
+    /// Each printed element that does not start with a `-` (or `+`) sign gets a
+    /// leading space, so that columns of positive and negative numbers line up, like
+    /// NumPy does. The sign is read from the formatted element, so this works for
+    /// any element type. The space is part of the element when padding it to the
+    /// width of the formatter or aligning it on the decimal point.
+    ///
+    /// ```
+    /// use ndarray::{array, FormatOptions};
+    ///
+    /// let a = array![[1, -2], [-3, 4]];
+    /// let opts = FormatOptions::new().sign_space(true);
+    /// assert_eq!(format!("{}", a.display_with(&opts)), "[[ 1, -2],\n [-3,  4]]");
+    /// ```
+    pub fn sign_space(mut self, sign_space: bool) -> Self {
+        self.sign_space = sign_space;
+        self
+    }
+
+    pub(crate) fn default_for_array(nelem: usize, no_limit: bool) -> Self {
+        Self::new().for_array(nelem, no_limit)
+    }
+
+    fn for_array(&self, nelem: usize, no_limit: bool) -> Self {
+        let few_elements = !self.always_summarize && nelem < ARRAY_MANY_ELEMENT_LIMIT;
+        self.clone().set_no_limit(no_limit || few_elements)
     }
 
     fn set_no_limit(mut self, no_limit: bool) -> Self {
@@ -110,22 +208,170 @@ fn format_with_overflow(
     Ok(())
 }
 
+/// Return the indices of the items of a list of `length` that are printed when
+/// the list overflows at `limit`; this must match `format_with_overflow`.
+fn printed_indices(length: usize, limit: usize) -> impl Iterator<Item = usize> {
+    let (head, tail) = if length <= limit {
+        (length, 0)
+    } else {
+        let edge = limit / 2;
+        (usize::max(edge, 1), edge)
+    };
+    (0..head).chain(length - tail..length)
+}
+
+/// Call `visit` for each element of `view` that is printed with `fmt_opt`, in order,
+/// along with its index (which is stored in `index[depth..]`).
+fn for_each_printed<A>(
+    view: ArrayView<A, IxDyn>,
+    fmt_opt: &FormatOptions,
+    depth: usize,
+    full_ndim: usize,
+    index: &mut [Ix],
+    visit: &mut dyn FnMut(&[Ix], &A) -> fmt::Result,
+) -> fmt::Result {
+    if view.is_empty() {
+        return Ok(());
+    }
+    if view.ndim() == 0 {
+        return visit(index, &view[[]]);
+    }
+    let limit = fmt_opt.collapse_limit(full_ndim - depth - 1);
+    for i in printed_indices(view.len_of(Axis(0)), limit) {
+        index[depth] = i;
+        for_each_printed(view.index_axis(Axis(0), i), fmt_opt, depth + 1, full_ndim, index, visit)?;
+    }
+    Ok(())
+}
+
+/// Format an element using `write_elt` into a string, passing on the precision
+/// and sign flags of `f`, and adding the sign space if enabled in `fmt_opt`.
+fn render(
+    f: &fmt::Formatter<'_>,
+    fmt_opt: &FormatOptions,
+    write_elt: &mut dyn FnMut(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> Result<String, fmt::Error> {
+    struct Element<'a, 'b>(RefCell<&'a mut (dyn FnMut(&mut fmt::Formatter<'_>) -> fmt::Result + 'b)>);
+
+    impl fmt::Display for Element<'_, '_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            (self.0.borrow_mut())(f)
+        }
+    }
+
+    let elt = Element(RefCell::new(write_elt));
+    let mut s = String::new();
+    match (f.precision(), f.sign_plus()) {
+        (Some(prec), false) => write!(s, "{:.*}", prec, elt)?,
+        (Some(prec), true) => write!(s, "{:+.*}", prec, elt)?,
+        (None, false) => write!(s, "{}", elt)?,
+        (None, true) => write!(s, "{:+}", elt)?,
+    }
+    if fmt_opt.sign_space && !s.starts_with(&['-', '+'][..]) {
+        s.insert(0, ' ');
+    }
+    Ok(s)
+}
+
+/// Split a rendered element at its decimal point (which starts the second part).
+fn split_decimal(s: &str) -> (&str, &str) {
+    match s.find('.') {
+        Some(i) => s.split_at(i),
+        None => (s, ""),
+    }
+}
+
+/// Write `n` copies of `c`.
+fn write_fill(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+/// Write a rendered element, padded to `int_width` and `frac_width` around its
+/// decimal point, and then to the width of the formatter.
+fn write_aligned(
+    f: &mut fmt::Formatter<'_>,
+    cell: &str,
+    int_width: usize,
+    frac_width: usize,
+) -> fmt::Result {
+    let (int_part, frac_part) = split_decimal(cell);
+    let int_pad = int_width.saturating_sub(int_part.chars().count());
+    let frac_pad = frac_width.saturating_sub(frac_part.chars().count());
+    let len = int_pad + cell.chars().count() + frac_pad;
+    let outer_pad = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, outer_pad),
+        Some(fmt::Alignment::Center) => (outer_pad / 2, outer_pad - outer_pad / 2),
+        _ => (outer_pad, 0),
+    };
+    let fill = f.fill();
+    write_fill(f, fill, before)?;
+    write_fill(f, ' ', int_pad)?;
+    f.write_str(cell)?;
+    write_fill(f, ' ', frac_pad)?;
+    write_fill(f, fill, after)
+}
+
 fn format_array<A, S, D, F>(
     array: &ArrayBase<S, D>,
     f: &mut fmt::Formatter<'_>,
-    format: F,
+    mut format: F,
     fmt_opt: &FormatOptions,
 ) -> fmt::Result
 where
     F: FnMut(&A, &mut fmt::Formatter<'_>) -> fmt::Result + Clone,
     D: Dimension,
     S: Data<Elem = A>,
+{
+    format_array_indexed(array, f, move |_: &[Ix], elt: &A, f: &mut fmt::Formatter<'_>| format(elt, f), fmt_opt)
+}
+
+/// Like `format_array`, but `format` also receives the index of the element.
+fn format_array_indexed<A, S, D, F>(
+    array: &ArrayBase<S, D>,
+    f: &mut fmt::Formatter<'_>,
+    mut format: F,
+    fmt_opt: &FormatOptions,
+) -> fmt::Result
+where
+    F: FnMut(&[Ix], &A, &mut fmt::Formatter<'_>) -> fmt::Result + Clone,
+    D: Dimension,
+    S: Data<Elem = A>,
 {
     // Cast into a dynamically dimensioned view
     // This is required to be able to use `index_axis` for the recursive case
-    format_array_inner(array.view().into_dyn(), f, format, fmt_opt, 0, array.ndim())
+    let view = array.view().into_dyn();
+    let ndim = view.ndim();
+    let mut index = vec![0; ndim];
+    if fmt_opt.decimal_align || fmt_opt.sign_space {
+        let (mut int_width, mut frac_width) = (0, 0);
+        if fmt_opt.decimal_align {
+            // Measure the printed elements first; they are rendered again when they
+            // are written, so that nothing needs to be buffered.
+            let mut measure = format.clone();
+            for_each_printed(view.view(), fmt_opt, 0, ndim, &mut index, &mut |ix, elt| {
+                let cell = render(f, fmt_opt, &mut |f| measure(ix, elt, f))?;
+                let (int_part, frac_part) = split_decimal(&cell);
+                int_width = usize::max(int_width, int_part.chars().count());
+                frac_width = usize::max(frac_width, frac_part.chars().count());
+                Ok(())
+            })?;
+        }
+        let format_aligned = move |ix: &[Ix], elt: &A, f: &mut fmt::Formatter<'_>| {
+            let cell = render(f, fmt_opt, &mut |f| format(ix, elt, f))?;
+            write_aligned(f, &cell, int_width, frac_width)
+        };
+        format_array_inner(view, f, format_aligned, fmt_opt, 0, ndim, &mut index)
+    } else {
+        format_array_inner(view, f, format, fmt_opt, 0, ndim, &mut index)
+    }
 }
 
+/// Format `view`, which is at `depth` in an array with `full_ndim` axes; the
+/// indices of the elements are passed to `format`, and stored in `index[depth..]`.
 fn format_array_inner<A, F>(
     view: ArrayView<A, IxDyn>,
     f: &mut fmt::Formatter<'_>,
@@ -133,9 +379,10 @@ fn format_array_inner<A, F>(
     fmt_opt: &FormatOptions,
     depth: usize,
     full_ndim: usize,
+    index: &mut [Ix],
 ) -> fmt::Result
 where
-    F: FnMut(&A, &mut fmt::Formatter<'_>) -> fmt::Result + Clone,
+    F: FnMut(&[Ix], &A, &mut fmt::Formatter<'_>) -> fmt::Result + Clone,
 {
     // If any of the axes has 0 length, we return the same empty array representation
     // e.g. [[]] for 2-d arrays
@@ -145,7 +392,7 @@ where
     }
     match view.shape() {
         // If it's 0 dimensional, we just print out the scalar
-        &[] => format(&view[[]], f)?,
+        &[] => format(index, &view[[]], f)?,
         // We handle 1-D arrays as a special case
         &[len] => {
             let view = view.view().into_dimensionality::<Ix1>().unwrap();
@@ -156,7 +403,10 @@ where
                 fmt_opt.collapse_limit(0),
                 ", ",
                 ELLIPSIS,
-                &mut |f, index| format(&view[index], f),
+                &mut |f, i| {
+                    index[depth] = i;
+                    format(index, &view[i], f)
+                },
             )?;
             f.write_str("]")?;
         }
@@ -168,14 +418,16 @@ where
 
             f.write_str("[")?;
             let limit = fmt_opt.collapse_limit(full_ndim - depth - 1);
-            format_with_overflow(f, shape[0], limit, &separator, ELLIPSIS, &mut |f, index| {
+            format_with_overflow(f, shape[0], limit, &separator, ELLIPSIS, &mut |f, i| {
+                index[depth] = i;
                 format_array_inner(
-                    view.index_axis(Axis(0), index),
+                    view.index_axis(Axis(0), i),
                     f,
                     format.clone(),
                     fmt_opt,
                     depth + 1,
                     full_ndim,
+                    index,
                 )
             })?;
             f.write_str("]")?;
@@ -199,6 +451,369 @@ where
     }
 }
 
+/// An array formatted with [`FormatOptions`].
+///
+/// This is created by [`.display_with()`](ArrayBase::display_with).
+pub struct DisplayWith<'a, A, D> {
+    view: ArrayView<'a, A, D>,
+    options: FormatOptions,
+}
+
+/// Format the array using `Display` for the elements, with the formatting options.
+impl<A: fmt::Display, D: Dimension> fmt::Display for DisplayWith<'_, A, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fmt_opt = self.options.for_array(self.view.len(), f.alternate());
+        format_array(&self.view, f, <_>::fmt, &fmt_opt)
+    }
+}
+
+/// An array formatted with `Debug` for the elements, without the shape and
+/// layout information.
+///
+/// This is created by [`.debug_values()`](ArrayBase::debug_values).
+pub struct DebugValues<'a, A, D> {
+    view: ArrayView<'a, A, D>,
+}
+
+/// Format the array using `Debug` for the elements, without any extra information.
+impl<A: fmt::Debug, D: Dimension> fmt::Debug for DebugValues<'_, A, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fmt_opt = FormatOptions::default_for_array(self.view.len(), f.alternate());
+        format_array(&self.view, f, <_>::fmt, &fmt_opt)
+    }
+}
+
+/// An array formatted with a function that receives the index of each element.
+///
+/// This is created by [`.format_with_index()`](ArrayBase::format_with_index).
+pub struct FormatWithIndex<'a, A, D, F> {
+    view: ArrayView<'a, A, D>,
+    options: FormatOptions,
+    format: F,
+}
+
+/// Format the array using the element formatting function, with the formatting options.
+impl<A, D, F> fmt::Display for FormatWithIndex<'_, A, D, F>
+where
+    D: Dimension,
+    F: Fn(D::Pattern, &A, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fmt_opt = self.options.for_array(self.view.len(), f.alternate());
+        let format = &self.format;
+        let ndim = self.view.ndim();
+        let format_indexed = move |index: &[Ix], elt: &A, f: &mut fmt::Formatter<'_>| {
+            let mut ix = D::zeros(ndim);
+            ix.slice_mut().copy_from_slice(index);
+            format(ix.into_pattern(), elt, f)
+        };
+        format_array_indexed(&self.view, f, format_indexed, &fmt_opt)
+    }
+}
+
+/// # Formatting
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a value that formats the array like its `Display` implementation, but
+    /// with the formatting options `options`.
+    ///
+    /// As with `Display`, the formatting parameters (like `{:.2}`) are used for each
+    /// element, and the alternate flag (`{:#}`) turns off the omission of elements of
+    /// large arrays.
+    ///
+    /// ```
+    /// use ndarray::{array, FormatOptions};
+    ///
+    /// let a = array![0.5, -12., 3.25];
+    /// let opts = FormatOptions::new().decimal_align(true);
+    /// assert_eq!(format!("{}", a.display_with(&opts)), "[  0.5 , -12   ,   3.25]");
+    /// assert_eq!(format!("{:.1}", a.display_with(&opts)), "[  0.5, -12.0,   3.2]");
+    /// ```
+    pub fn display_with(&self, options: &FormatOptions) -> DisplayWith<'_, A, D> {
+        DisplayWith {
+            view: self.view(),
+            options: options.clone(),
+        }
+    }
+
+    /// Return a value that formats the array like its `Debug` implementation, but
+    /// only the elements: without the shape, strides and layout that follow them.
+    ///
+    /// This is useful for comparing the formatted values of arrays, for example in tests.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1.5, 2.], [3., 4.]];
+    /// assert_eq!(format!("{:?}", a.debug_values()), "[[1.5, 2.0],\n [3.0, 4.0]]");
+    /// assert!(format!("{:?}", a).starts_with("[[1.5, 2.0],\n [3.0, 4.0]], shape=[2, 2]"));
+    /// ```
+    pub fn debug_values(&self) -> DebugValues<'_, A, D> {
+        DebugValues { view: self.view() }
+    }
+
+    /// Return a value that formats the array with the formatting options `options`,
+    /// using `format` to format each element, given its index and value.
+    ///
+    /// The index is in the pattern form of the dimension, for example `(i, j)` for a
+    /// two-dimensional array. The formatting parameters (like `{:.2}`) are available
+    /// to `format` through the formatter, as for `Display`.
+    ///
+    /// ```
+    /// use ndarray::{array, FormatOptions};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// // mark the diagonal
+    /// let s = a.format_with_index(&FormatOptions::new(), |(i, j), x, f| {
+    ///     if i == j { write!(f, "<{}>", x) } else { write!(f, "{}", x) }
+    /// });
+    /// assert_eq!(s.to_string(), "[[<1>, 2],\n [3, <4>]]");
+    /// ```
+    pub fn format_with_index<F>(&self, options: &FormatOptions, format: F)
+        -> FormatWithIndex<'_, A, D, F>
+    where
+        F: Fn(D::Pattern, &A, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        FormatWithIndex {
+            view: self.view(),
+            options: options.clone(),
+            format,
+        }
+    }
+
+    /// Format the array using `Display` into a string of at most `max_chars`
+    /// characters.
+    ///
+    /// If the whole array does not fit, the number of items printed along each axis
+    /// is reduced (see [`FormatOptions::elements_limit`]) to the largest limit whose
+    /// result fits, found by bisection. If not even a single item per axis fits, the
+    /// result is a summary like `<Array shape=[2, 3]>` (which may itself be longer
+    /// than `max_chars`).
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_shape_fn(100, |i| i as f64);
+    /// assert_eq!(a.summarize_to_width(30), "[0, 1, 2, ..., 97, 98, 99]");
+    /// assert_eq!(a.summarize_to_width(10), "[0, ...]");
+    /// assert_eq!(a.summarize_to_width(5), "<Array shape=[100]>");
+    /// ```
+    pub fn summarize_to_width(&self, max_chars: usize) -> String
+    where
+        A: fmt::Display,
+    {
+        /// A string that refuses to grow beyond `max_chars` characters.
+        struct Bounded {
+            s: String,
+            chars: usize,
+            max_chars: usize,
+        }
+
+        impl fmt::Write for Bounded {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.chars += s.chars().count();
+                if self.chars > self.max_chars {
+                    return Err(fmt::Error);
+                }
+                self.s.push_str(s);
+                Ok(())
+            }
+        }
+
+        // Writing stops as soon as the budget is exceeded, so each attempt costs at
+        // most `max_chars` characters of output.
+        let render = |limit: usize| {
+            let mut out = Bounded { s: String::new(), chars: 0, max_chars };
+            let opts = FormatOptions::new().elements_limit(limit);
+            write!(out, "{}", self.display_with(&opts)).ok().map(|_| out.s)
+        };
+
+        let max_len = self.shape().iter().cloned().max().unwrap_or(0);
+        if let Some(full) = render(usize::max(max_len, 1)) {
+            return full;
+        }
+        let mut best = match render(1) {
+            Some(s) => s,
+            None => return format!("<Array shape={:?}>", self.shape()),
+        };
+        // the largest limit that fits is in lo..hi
+        let (mut lo, mut hi) = (1, max_len);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            match render(mid) {
+                Some(s) => {
+                    best = s;
+                    lo = mid;
+                }
+                None => hi = mid,
+            }
+        }
+        best
+    }
+}
+
+/// Return the least and the greatest finite value, or `None` if there are none.
+fn finite_range<'a>(iter: impl Iterator<Item = &'a f64>) -> Option<(f64, f64)> {
+    iter.filter(|x| x.is_finite())
+        .fold(None, |range, &x| match range {
+            None => Some((x, x)),
+            Some((min, max)) => Some((f64::min(min, x), f64::max(max, x))),
+        })
+}
+
+/// The characters of a sparkline, from the lowest to the highest level.
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// # Text Plots for 1-D Arrays
+impl<S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = f64>,
+{
+    /// Render the array as a sparkline: a string with one Unicode block
+    /// character (`▁▂▃▄▅▆▇█`) per element, whose height is scaled between the
+    /// least and the greatest finite element.
+    ///
+    /// If all finite elements are equal, they are drawn at a middle level (`▄`).
+    /// Positive and negative infinity are drawn at the highest and lowest level,
+    /// and NaN is drawn as a space. An empty array gives an empty string.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0., 1., 2., 3., 4., 5., 6., 7., f64::NAN, 3.5];
+    /// assert_eq!(a.sparkline(), "▁▂▃▄▅▆▇█ ▅");
+    /// assert_eq!(array![2., 2.].sparkline(), "▄▄");
+    /// ```
+    pub fn sparkline(&self) -> String {
+        let range = finite_range(self.iter());
+        let top = SPARK_LEVELS.len() - 1;
+        self.iter()
+            .map(|&x| {
+                if x.is_nan() {
+                    return ' ';
+                }
+                let level = if x == f64::INFINITY {
+                    top
+                } else if x == f64::NEG_INFINITY {
+                    0
+                } else {
+                    match range {
+                        Some((min, max)) if min < max => {
+                            let t = (x - min) / (max - min);
+                            (t * top as f64 + 0.5) as usize
+                        }
+                        _ => top / 2,
+                    }
+                };
+                SPARK_LEVELS[level]
+            })
+            .collect()
+    }
+}
+
+/// The colormap of a heatmap drawn by [`.heatmap_string()`](ArrayBase::heatmap_string).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColorMap {
+    /// The perceptually uniform colormap from dark purple over blue and green
+    /// to yellow
+    Viridis,
+    /// Black to white
+    Gray,
+}
+
+/// The color of NaN elements in a heatmap.
+const HEATMAP_NAN_COLOR: [u8; 3] = [255, 0, 0];
+
+/// Samples of the viridis colormap at equal distances, to interpolate between.
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 45, 123],
+    [59, 82, 139],
+    [44, 114, 142],
+    [33, 145, 140],
+    [40, 174, 128],
+    [94, 201, 98],
+    [173, 220, 48],
+    [253, 231, 37],
+];
+
+impl ColorMap {
+    /// Return the color at `t`, which is in `0. ..= 1.`.
+    fn color(self, t: f64) -> [u8; 3] {
+        match self {
+            ColorMap::Viridis => {
+                let pos = t * (VIRIDIS.len() - 1) as f64;
+                let i = usize::min(pos as usize, VIRIDIS.len() - 2);
+                let frac = pos - i as f64;
+                let mut rgb = [0; 3];
+                for (c, (&lo, &hi)) in rgb.iter_mut().zip(VIRIDIS[i].iter().zip(&VIRIDIS[i + 1])) {
+                    *c = (lo as f64 + frac * (hi as f64 - lo as f64) + 0.5) as u8;
+                }
+                rgb
+            }
+            ColorMap::Gray => {
+                let v = (t * 255. + 0.5) as u8;
+                [v, v, v]
+            }
+        }
+    }
+}
+
+/// # Text Plots for 2-D Arrays
+impl<S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = f64>,
+{
+    /// Render the array as a heatmap for a terminal: each element is drawn as
+    /// a space with an ANSI 24-bit background color, and each row ends with a
+    /// newline.
+    ///
+    /// The colors are taken from `colormap`, with the least finite element at
+    /// the low end and the greatest finite element at the high end. If all
+    /// finite elements are equal, they get the middle color. Positive and
+    /// negative infinity get the colors of the high and low end, and NaN is
+    /// drawn in red.
+    ///
+    /// ```
+    /// use ndarray::{array, ColorMap};
+    ///
+    /// let a = array![[0., 1.], [f64::NAN, 0.5]];
+    /// let s = a.heatmap_string(ColorMap::Gray);
+    /// assert_eq!(s, "\x1b[48;2;0;0;0m \x1b[48;2;255;255;255m \x1b[0m\n\
+    ///                \x1b[48;2;255;0;0m \x1b[48;2;128;128;128m \x1b[0m\n");
+    /// ```
+    pub fn heatmap_string(&self, colormap: ColorMap) -> String {
+        let range = finite_range(self.iter());
+        let mut s = String::new();
+        for row in self.rows() {
+            for &x in row {
+                let rgb = if x.is_nan() {
+                    HEATMAP_NAN_COLOR
+                } else {
+                    let t = if x == f64::INFINITY {
+                        1.
+                    } else if x == f64::NEG_INFINITY {
+                        0.
+                    } else {
+                        match range {
+                            Some((min, max)) if min < max => (x - min) / (max - min),
+                            _ => 0.5,
+                        }
+                    };
+                    colormap.color(t)
+                };
+                let _ = write!(s, "\x1b[48;2;{};{};{}m ", rgb[0], rgb[1], rgb[2]);
+            }
+            s.push_str("\x1b[0m\n");
+        }
+        s
+    }
+}
+
 /// Format the array using `Debug` and apply the formatting parameters used
 /// to each element.
 ///
@@ -8,6 +8,7 @@
 
 use alloc::boxed::Box;
 use alloc::vec::Vec;
+use std::cmp::Ordering;
 use std::iter::IntoIterator;
 use std::mem;
 use std::ops::{Index, IndexMut};
@@ -18,7 +19,7 @@ use crate::imp_prelude::*;
 use crate::{
     dimension,
     iter::{Iter, IterMut},
-    numeric_util, FoldWhile, NdIndex, Zip,
+    numeric_util, FoldWhile, NdIndex, ShapeError, StrideShape, Zip,
 };
 
 #[cold]
@@ -149,6 +150,62 @@ where
 {
 }
 
+/// Compare arrays lexicographically, first by shape and then by elements.
+///
+/// The shapes are compared first, as slices of axis lengths: the array with the shorter axis
+/// length at the first axis where they differ is less, and if one shape is a prefix of the
+/// other (possible with dynamic dimensionality), the array with fewer axes is less.
+/// For example, shape `[2, 5]` is less than `[3, 1]` and `[2]` is less than `[2, 1]`.
+///
+/// Arrays of the same shape are compared element by element in logical order, and the first
+/// pair of elements that are not equal decides the ordering, like for slices.
+/// Arrays compare equal exactly when they are equal by `PartialEq`.
+impl<A, B, S, S2, D> PartialOrd<ArrayBase<S2, D>> for ArrayBase<S, D>
+where
+    A: PartialOrd<B>,
+    S: Data<Elem = A>,
+    S2: Data<Elem = B>,
+    D: Dimension,
+{
+    fn partial_cmp(&self, rhs: &ArrayBase<S2, D>) -> Option<Ordering> {
+        match self.shape().cmp(rhs.shape()) {
+            Ordering::Equal => {}
+            non_eq => return Some(non_eq),
+        }
+        for (a, b) in self.iter().zip(rhs) {
+            match a.partial_cmp(b) {
+                Some(Ordering::Equal) => {}
+                non_eq => return non_eq,
+            }
+        }
+        Some(Ordering::Equal)
+    }
+}
+
+/// Compare arrays lexicographically, first by shape and then by elements.
+///
+/// See the `PartialOrd` implementation for the details of the ordering.
+impl<S, D> Ord for ArrayBase<S, D>
+where
+    D: Dimension,
+    S: Data,
+    S::Elem: Ord,
+{
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match self.shape().cmp(rhs.shape()) {
+            Ordering::Equal => {}
+            non_eq => return non_eq,
+        }
+        for (a, b) in self.iter().zip(rhs) {
+            match a.cmp(b) {
+                Ordering::Equal => {}
+                non_eq => return non_eq,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
 impl<A, S> From<Box<[A]>> for ArrayBase<S, Ix1>
 where
     S: DataOwned<Elem = A>,
@@ -258,21 +315,15 @@ where
     S: Data,
     S::Elem: hash::Hash,
 {
-    // Note: elements are hashed in the logical order
+    // Note: elements are hashed in the logical order, one at a time.
+    //
+    // `Hash::hash_slice` may feed a whole slice to the hasher in one write, and not all hashers
+    // give the same result for one large write as for several small ones; hashing each element
+    // separately makes equal arrays hash equally regardless of their memory layout.
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.shape().hash(state);
-        if let Some(self_s) = self.as_slice() {
-            hash::Hash::hash_slice(self_s, state);
-        } else {
-            for row in self.rows() {
-                if let Some(row_s) = row.as_slice() {
-                    hash::Hash::hash_slice(row_s, state);
-                } else {
-                    for elt in row {
-                        elt.hash(state)
-                    }
-                }
-            }
+        for elt in self.iter() {
+            elt.hash(state)
         }
     }
 }
@@ -457,6 +508,46 @@ where
 {
 }
 
+/// Extension trait for collecting an iterator into an array of a given shape.
+///
+/// This trait is implemented for all iterators, and is private to implement.
+///
+/// ```
+/// use ndarray::prelude::*;
+///
+/// let a = (0..6).map(|x| x * x).collect_array((2, 3)).unwrap();
+/// assert_eq!(a, array![[0, 1, 4], [9, 16, 25]]);
+///
+/// // the number of elements must match the shape
+/// assert!((0..5).collect_array((2, 3)).is_err());
+/// ```
+pub trait CollectArray<A>: Iterator<Item = A> {
+    /// Collect the elements of the iterator into an array of shape `shape`.
+    ///
+    /// This is a shorthand for collecting into a `Vec` and using
+    /// [`Array::from_shape_vec`], so the same rules for the shape apply.
+    ///
+    /// **Errors** if the number of elements does not match the shape, or if
+    /// the shape would result in overflowing `isize`.
+    fn collect_array<Sh, D>(self, shape: Sh) -> Result<Array<A, D>, ShapeError>
+    where
+        Sh: Into<StrideShape<D>>,
+        D: Dimension,
+        Self: Sized,
+    {
+        Array::from_shape_vec(shape, self.collect())
+    }
+
+    private_decl! {}
+}
+
+impl<A, I> CollectArray<A> for I
+where
+    I: Iterator<Item = A>,
+{
+    private_impl! {}
+}
+
 /// Create an owned array with a default state.
 ///
 /// The array is created with dimension `D::default()`, which results
@@ -82,6 +82,27 @@ impl<A> OwnedRepr<A> {
         self.as_nonnull_mut()
     }
 
+    /// Truncate the data to `len` elements, dropping the rest.
+    ///
+    /// This does not reallocate, so existing pointers to the retained
+    /// elements stay valid.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.modify_as_vec(|mut v| {
+            v.truncate(len);
+            v
+        });
+    }
+
+    /// Shrink the capacity of the data to its length, returning the (possibly
+    /// moved) data pointer.
+    pub(crate) fn shrink_to_fit(&mut self) -> NonNull<A> {
+        self.modify_as_vec(|mut v| {
+            v.shrink_to_fit();
+            v
+        });
+        self.as_nonnull_mut()
+    }
+
     /// Set the valid length of the data
     ///
     /// ## Safety
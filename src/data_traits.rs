@@ -29,6 +29,13 @@ use crate::{
 /// ***Note:*** `RawData` is not an extension interface at this point.
 /// Traits in Rust can serve many different roles. This trait is public because
 /// it is used as a bound on public methods.
+//
+// # For implementers
+//
+// The elements must not be stored inline in the representation (as they would be
+// for `[A; N]`): the array keeps a separate pointer to its first element, which
+// would dangle as soon as the array is moved. Stack-allocated elements are used by
+// borrowing them, for example with `ArrayView::from_shape(shape, &elements)`.
 #[allow(clippy::missing_safety_doc)] // not implementable downstream
 pub unsafe trait RawData: Sized {
     /// The array element type.
@@ -77,6 +84,16 @@ pub unsafe trait RawDataMut: RawData {
 ///
 /// An array representation that can be cloned.
 ///
+/// This is not implemented for mutable views (`ViewRepr<&mut A>`), and it can't
+/// soundly be: a clone of a mutable view would be a second mutable view of the same
+/// elements, which would allow two simultaneous mutable borrows of them. A
+/// reborrow only avoids that if the original is frozen for the lifetime of the
+/// copy, which a by-`&self` clone can't express. Generic code that needs to
+/// duplicate any array with `S: Data` should use [`.view()`](ArrayBase::view),
+/// which reborrows the array as an (always cloneable) read-only view, or
+/// [`.to_owned()`](ArrayBase::to_owned) to copy the elements. For mutable views,
+/// [`.reborrow()`](ArrayBase::reborrow) gives a shorter-lived mutable view.
+///
 /// ***Internal trait, see `RawData`.***
 #[allow(clippy::missing_safety_doc)] // not implementable downstream
 pub unsafe trait RawDataClone: RawData {
@@ -156,7 +173,35 @@ pub unsafe trait DataMut: Data + RawDataMut {
         Self: Sized,
         D: Dimension,
     {
-        Self::try_ensure_unique(self_)
+        Self::ensure_unique_copied(self_);
+    }
+
+    /// Ensures that the array has unique access to its data, and returns
+    /// whether the elements had to be copied to achieve that.
+    ///
+    /// A copy happens only for shared (copy on write) storage that is not
+    /// uniquely held, such as an `ArcArray` whose data is also referenced by
+    /// another array, or a `CowArray` that is a view. This makes it possible to
+    /// log or account for copy on write events.
+    ///
+    /// ```
+    /// use ndarray::{ArcArray, DataMut, OwnedArcRepr};
+    ///
+    /// let mut a = ArcArray::from_elem((2, 2), 1.);
+    /// let b = a.clone();
+    /// assert!(OwnedArcRepr::ensure_unique_copied(&mut a));
+    /// assert!(!OwnedArcRepr::ensure_unique_copied(&mut a));
+    /// # drop(b);
+    /// ```
+    #[inline]
+    fn ensure_unique_copied<D>(self_: &mut ArrayBase<Self, D>) -> bool
+    where
+        Self: Sized,
+        D: Dimension,
+    {
+        let copied = !self_.data.is_unique();
+        Self::try_ensure_unique(self_);
+        copied
     }
 
     /// Returns whether the array has unique access to its data.
@@ -526,6 +571,24 @@ pub unsafe trait DataOwned: Data {
     /// representation, without any copying.
     #[doc(hidden)]
     fn into_shared(self) -> OwnedArcRepr<Self::Elem>;
+
+    /// Converts the data representation into a `Vec` of all the elements of
+    /// its storage.
+    ///
+    /// The elements are in storage order, which is not necessarily the logical
+    /// order of an array using this storage, and the vector also includes any
+    /// elements that the array has been sliced away from. Unique ownership
+    /// storage is converted without copying; shared storage is cloned if it is
+    /// not uniquely held.
+    ///
+    /// The `Clone` bound is needed for that last case: other arrays still use
+    /// shared storage, so its elements can't be moved out. For `Array`, whose
+    /// storage is always unique, use
+    /// [`Array::into_raw_vec`](crate::Array::into_raw_vec), which has no
+    /// such bound.
+    fn into_vec(self) -> Vec<Self::Elem>
+    where
+        Self::Elem: Clone;
 }
 
 /// Array representation trait.
@@ -549,6 +612,13 @@ unsafe impl<A> DataOwned for OwnedRepr<A> {
     fn into_shared(self) -> OwnedArcRepr<A> {
         OwnedArcRepr(Arc::new(self))
     }
+
+    fn into_vec(self) -> Vec<A>
+    where
+        A: Clone,
+    {
+        OwnedRepr::into_vec(self)
+    }
 }
 
 unsafe impl<A> DataOwned for OwnedArcRepr<A> {
@@ -561,6 +631,16 @@ unsafe impl<A> DataOwned for OwnedArcRepr<A> {
     fn into_shared(self) -> OwnedArcRepr<A> {
         self
     }
+
+    fn into_vec(self) -> Vec<A>
+    where
+        A: Clone,
+    {
+        match Arc::try_unwrap(self.0) {
+            Ok(owned_data) => owned_data.into_vec(),
+            Err(arc_data) => arc_data.as_slice().to_vec(),
+        }
+    }
 }
 
 unsafe impl<'a, A> RawData for CowRepr<'a, A> {
@@ -17,6 +17,28 @@ pub struct Geomspace<F> {
     step: F,
     index: usize,
     len: usize,
+    /// Exact value of the first element
+    first: F,
+    /// Index and exact value of the last element, if distinct from the first
+    last: Option<(usize, F)>,
+}
+
+impl<F> Geomspace<F>
+where
+    F: Float,
+{
+    #[inline]
+    fn value(&self, i: usize) -> F {
+        match self.last {
+            _ if i == 0 => self.first,
+            Some((last, end)) if i == last => end,
+            _ => {
+                // Calculate the value just like numpy.linspace does
+                let exponent = self.start + self.step * F::from(i).unwrap();
+                self.sign * exponent.exp()
+            }
+        }
+    }
 }
 
 impl<F> Iterator for Geomspace<F>
@@ -30,11 +52,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             let i = self.index;
             self.index += 1;
-            let exponent = self.start + self.step * F::from(i).unwrap();
-            Some(self.sign * exponent.exp())
+            Some(self.value(i))
         }
     }
 
@@ -54,11 +74,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             self.len -= 1;
             let i = self.len;
-            let exponent = self.start + self.step * F::from(i).unwrap();
-            Some(self.sign * exponent.exp())
+            Some(self.value(i))
         }
     }
 }
@@ -68,7 +86,8 @@ impl<F> ExactSizeIterator for Geomspace<F> where Geomspace<F>: Iterator {}
 /// An iterator of a sequence of geometrically spaced values.
 ///
 /// The `Geomspace` has `n` geometrically spaced elements from `start` to `end`
-/// (inclusive).
+/// (inclusive). The first element is exactly `start` and, if `n > 1`, the last
+/// element is exactly `end`.
 ///
 /// The iterator element type is `F`, where `F` must implement `Float`, e.g.
 /// `f32` or `f64`.
@@ -88,11 +107,11 @@ where
     }
     let log_a = a.abs().ln();
     let log_b = b.abs().ln();
-    let step = if n > 1 {
+    let (step, last) = if n > 1 {
         let num_steps = F::from(n - 1).expect("Converting number of steps to `A` must not fail.");
-        (log_b - log_a) / num_steps
+        ((log_b - log_a) / num_steps, Some((n - 1, b)))
     } else {
-        F::zero()
+        (F::zero(), None)
     };
     Some(Geomspace {
         sign: a.signum(),
@@ -100,6 +119,8 @@ where
         step,
         index: 0,
         len: n,
+        first: a,
+        last,
     })
 }
 
@@ -156,6 +177,25 @@ mod tests {
         assert!(iter.size_hint() == (0, Some(0)));
     }
 
+    #[test]
+    fn exact_endpoints() {
+        let v: Vec<f64> = geomspace(0.3, 7e5, 9).unwrap().collect();
+        assert_eq!(v.len(), 9);
+        assert_eq!(v[0], 0.3);
+        assert_eq!(v[8], 7e5);
+
+        let v: Vec<f64> = geomspace(-7e5, -0.3, 9).unwrap().rev().collect();
+        assert_eq!(v[0], -0.3);
+        assert_eq!(v[8], -7e5);
+    }
+
+    #[test]
+    fn short() {
+        assert_eq!(geomspace(2., 8., 0).unwrap().count(), 0);
+        assert_eq!(geomspace(2., 8., 1).unwrap().collect::<Vec<f64>>(), vec![2.]);
+        assert_eq!(geomspace(2., 8., 2).unwrap().collect::<Vec<f64>>(), vec![2., 8.]);
+    }
+
     #[test]
     fn zero_lower() {
         assert!(geomspace(0.0, 1.0, 4).is_none());
@@ -170,4 +210,10 @@ mod tests {
     fn zero_included() {
         assert!(geomspace(-1.0, 1.0, 4).is_none());
     }
+
+    #[test]
+    fn opposite_signs() {
+        assert!(geomspace(1.0, -8.0, 4).is_none());
+        assert!(geomspace(-1.0, 8.0, 1).is_none());
+    }
 }
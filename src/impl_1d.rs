@@ -12,6 +12,7 @@ use std::mem::MaybeUninit;
 
 use crate::imp_prelude::*;
 use crate::low_level_util::AbortIfPanic;
+use crate::Zip;
 
 /// # Methods For 1-D Arrays
 impl<A, S> ArrayBase<S, Ix1>
@@ -31,6 +32,34 @@ where
         }
     }
 
+    /// Apply `f` to each contiguous window of `window` elements and collect the results
+    /// into a new array.
+    ///
+    /// The result has `len - window + 1` elements, where element `i` is `f` applied to the
+    /// window starting at index `i`. This is a shorthand for reducing each item of
+    /// [`.windows()`](ArrayBase::windows).
+    ///
+    /// **Panics** if `window` is zero or larger than the length of the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1, 2, 3, 4, 5];
+    /// let sums = a.rolling_apply(3, |w| w.sum());
+    /// assert_eq!(sums, array![6, 9, 12]);
+    /// ```
+    pub fn rolling_apply<B, F>(&self, window: usize, f: F) -> Array<B, Ix1>
+    where
+        F: FnMut(ArrayView<'_, A, Ix1>) -> B,
+        S: Data,
+    {
+        assert!(window != 0, "ndarray: rolling_apply window must be nonzero");
+        assert!(window <= self.len(),
+                "ndarray: rolling_apply window {} is larger than the array length {}",
+                window, self.len());
+        Zip::from(self.windows(window)).map_collect(f)
+    }
+
     /// Rotate the elements of the array by 1 element towards the front;
     /// the former first element becomes the last.
     pub(crate) fn rotate1_front(&mut self)
@@ -30,6 +30,7 @@ use crate::indices;
 use crate::iterators::to_vec;
 use crate::iterators::to_vec_mapped;
 use crate::iterators::TrustedIterator;
+use crate::Slice;
 use crate::StrideShape;
 #[cfg(feature = "std")]
 use crate::{geomspace, linspace, logspace};
@@ -208,6 +209,26 @@ where
         eye
     }
 
+    /// Create an identity matrix of size `n` (square 2D array).
+    ///
+    /// This is an alias for [`eye`](ArrayBase::eye).
+    ///
+    /// **Panics** if `n * n` would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let m = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(Array2::identity(2).dot(&m), m);
+    /// ```
+    pub fn identity(n: Ix) -> Self
+    where
+        S: DataMut,
+        A: Clone + Zero + One,
+    {
+        Self::eye(n)
+    }
+
     /// Create a 2D matrix from its diagonal
     ///
     /// **Panics** if `diag.len() * diag.len()` would overflow `isize`.
@@ -231,6 +252,78 @@ where
         arr
     }
 
+    /// Create a 2D matrix with `diag` placed on the `k`-th diagonal and zeros
+    /// elsewhere.
+    ///
+    /// The diagonal is selected like in NumPy: `k > 0` is above the main
+    /// diagonal and `k < 0` below it. The result has shape `(n, n)` where
+    /// `n = diag.len() + |k|`.
+    ///
+    /// **Panics** if `n * n` would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{Array2, arr1, arr2};
+    ///
+    /// let diag = arr1(&[1, 2]);
+    /// let array = Array2::from_diag_with_offset(&diag, 1);
+    /// assert_eq!(array, arr2(&[[0, 1, 0], [0, 0, 2], [0, 0, 0]]));
+    ///
+    /// let array = Array2::from_diag_with_offset(&diag, -1);
+    /// assert_eq!(array, arr2(&[[0, 0, 0], [1, 0, 0], [0, 2, 0]]));
+    /// ```
+    pub fn from_diag_with_offset<S2>(diag: &ArrayBase<S2, Ix1>, k: isize) -> Self
+    where
+        A: Clone + Zero,
+        S: DataMut,
+        S2: Data<Elem = A>,
+    {
+        let offset = k.unsigned_abs();
+        let n = diag.len() + offset;
+        let mut arr = Self::zeros((n, n));
+        let axis = if k >= 0 { Axis(1) } else { Axis(0) };
+        arr.slice_axis_mut(axis, Slice::from(offset..))
+            .diag_mut()
+            .assign(diag);
+        arr
+    }
+
+    /// Create a 2D one-hot encoding of the class indices in `indices`.
+    ///
+    /// The result has shape `(indices.len(), num_classes)`; row `i` is all zeros
+    /// except for a one in column `indices[i]`.
+    ///
+    /// **Panics** if any index is greater than or equal to `num_classes`, or if
+    /// the number of elements would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let labels = array![2, 0, 1, 2];
+    /// let encoded = Array2::<f32>::one_hot(&labels, 3);
+    /// assert_eq!(encoded, array![[0., 0., 1.],
+    ///                            [1., 0., 0.],
+    ///                            [0., 1., 0.],
+    ///                            [0., 0., 1.]]);
+    /// ```
+    pub fn one_hot<S2>(indices: &ArrayBase<S2, Ix1>, num_classes: usize) -> Self
+    where
+        A: Clone + Zero + One,
+        S: DataMut,
+        S2: Data<Elem = usize>,
+    {
+        let mut arr = Self::zeros((indices.len(), num_classes));
+        for (mut row, &index) in arr.rows_mut().into_iter().zip(indices) {
+            assert!(
+                index < num_classes,
+                "ndarray: one_hot: index {} is out of range for {} classes",
+                index,
+                num_classes
+            );
+            row[index] = A::one();
+        }
+        arr
+    }
+
     /// Create a square 2D matrix of the specified size, with the specified
     /// element along the diagonal and zeros elsewhere.
     ///
@@ -334,6 +427,89 @@ where
         unsafe { Self::from_shape_vec_unchecked(shape, v) }
     }
 
+    /// Create an array with copies of `elem`, shape `shape`, whose first element is
+    /// aligned to `align` bytes.
+    ///
+    /// This is useful for SIMD kernels that require aligned loads and stores: the
+    /// pointer returned by [`.as_ptr()`](ArrayBase::as_ptr) is a multiple of `align`.
+    /// The array is in standard or Fortran layout as specified by `shape`, so with
+    /// `align` a multiple of the SIMD register width, each contiguous chunk of
+    /// that width starting at the first element is aligned too.
+    ///
+    /// The alignment is achieved by allocating a few more elements than needed
+    /// (which are also set to copies of `elem`) and starting the array at the
+    /// first aligned position in the allocation. Because of this:
+    ///
+    /// - The alignment is a property of this allocation only. Arrays created from
+    ///   this one, for example by `.clone()`, `.to_owned()` or `.map()`, use a
+    ///   normal allocation and are not guaranteed to be aligned beyond the
+    ///   alignment of `A`. Slicing the array in place moves the first element.
+    /// - [`.into_raw_vec()`](Array::into_raw_vec) returns the whole allocation,
+    ///   including the leading padding elements; a `Vec` does not track a custom
+    ///   alignment, and the padding makes it unsuitable as a replacement for the
+    ///   array's elements.
+    ///
+    /// **Panics** if `align` is not a power of two, if elements of type `A`
+    /// can't always be placed at a multiple of `align` (when the size of `A` is
+    /// a multiple of a larger power of two than its alignment, as for
+    /// `[f32; 2]`), or if the product of non-zero axis lengths overflows
+    /// `isize`. If `align` is at most the alignment of `A`, or `A` is
+    /// zero-sized, this is the same as [`Array::from_elem`].
+    ///
+    /// ```
+    /// use ndarray::{Array, Array2};
+    ///
+    /// let a = Array2::from_elem_aligned((3, 5), 1.0f32, 64);
+    /// assert_eq!(a.as_ptr() as usize % 64, 0);
+    /// assert_eq!(a, Array::from_elem((3, 5), 1.));
+    /// ```
+    pub fn from_elem_aligned<Sh>(shape: Sh, elem: A, align: usize) -> Self
+    where
+        A: Clone,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        assert!(
+            align.is_power_of_two(),
+            "ndarray: from_elem_aligned: alignment {} is not a power of two",
+            align
+        );
+        let elem_size = mem::size_of::<A>();
+        if elem_size == 0 || align <= mem::align_of::<A>() {
+            return Self::from_elem(shape, elem);
+        }
+        let shape = shape.into_shape();
+        let size = size_of_shape_checked_unwrap!(&shape.dim);
+
+        // The address of element `k` steps by `elem_size` modulo `align`, so it only
+        // reaches a multiple of `align` from every `A`-aligned address if `step`
+        // divides the alignment of `A`. The addresses repeat after `period` elements.
+        let step = Ord::min(elem_size & elem_size.wrapping_neg(), align);
+        assert!(
+            step <= mem::align_of::<A>(),
+            "ndarray: from_elem_aligned: elements of size {} can't be aligned to {} bytes",
+            elem_size,
+            align
+        );
+        let period = align / step;
+        let mut v = Vec::with_capacity(size + period);
+        let addr = v.as_ptr() as usize;
+        let pad = (0..period)
+            .find(|&k| (addr + k * elem_size) & (align - 1) == 0)
+            .unwrap();
+        // within the capacity, so the allocation does not move
+        v.resize(pad + size, elem);
+
+        let shape: StrideShape<D> = shape.into();
+        let dim = shape.dim;
+        let strides = shape.strides.strides_for_dim(&dim);
+        unsafe {
+            // Safe because the elements from `pad` on are a contiguous block of `size`
+            // elements, and the strides are the standard or Fortran order strides.
+            let ptr = nonnull_from_vec_data(&mut v).add(pad);
+            ArrayBase::from_data_ptr(DataOwned::new(v), ptr).with_strides_dim(strides, dim)
+        }
+    }
+
     /// Create an array with zeros, shape `shape`.
     ///
     /// **Panics** if the product of non-zero axis lengths overflows `isize`.
@@ -391,7 +567,9 @@ where
     /// Create an array with values created by the function `f`.
     ///
     /// `f` is called with the index of the element to create; the elements are
-    /// visited in arbitrary order.
+    /// visited in arbitrary order. If the order matters, for example because `f`
+    /// is stateful, use [`from_shape_fn_mut`](ArrayBase::from_shape_fn_mut)
+    /// instead.
     ///
     /// **Panics** if the product of non-zero axis lengths overflows `isize`.
     ///
@@ -425,6 +603,52 @@ where
         }
     }
 
+    /// Create an array with values created by the function `f`.
+    ///
+    /// `f` is called with the index of the element to create, and the elements
+    /// are visited in logical order: by increasing index, with the last axis
+    /// varying the fastest (row-major order). This is the case regardless of the
+    /// memory layout requested by `shape`, so `f` can use mutable state, like a
+    /// counter or a random number generator, in a predictable way.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{Array, ShapeBuilder, arr2};
+    ///
+    /// let mut counter = 0;
+    /// let a = Array::from_shape_fn_mut((2, 3).f(), |_| {
+    ///     counter += 1;
+    ///     counter
+    /// });
+    ///
+    /// assert_eq!(a, arr2(&[[1, 2, 3],
+    ///                      [4, 5, 6]]));
+    /// assert_eq!(a.strides(), &[1, 2]);
+    /// ```
+    pub fn from_shape_fn_mut<Sh, F>(shape: Sh, mut f: F) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        F: FnMut(D::Pattern) -> A,
+    {
+        let shape = shape.into_shape();
+        let _ = size_of_shape_checked_unwrap!(&shape.dim);
+        if shape.is_c() {
+            let v = to_vec_mapped(indices(shape.dim.clone()).into_iter(), f);
+            unsafe { Self::from_shape_vec_unchecked(shape, v) }
+        } else {
+            let dim = shape.dim.clone();
+            let array = Self::build_uninit(shape, move |view| {
+                // both iterators visit the elements in logical order
+                for (index, elt) in indices(dim).into_iter().zip(view) {
+                    *elt = MaybeUninit::new(f(index));
+                }
+            });
+            // Safe because: all elements were initialized by the builder
+            unsafe { array.assume_init() }
+        }
+    }
+
     /// Create an array with the given shape from a vector. (No cloning of
     /// elements needed.)
     ///
@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::cmp::Ordering;
 use std::mem::{size_of, ManuallyDrop};
 use alloc::slice;
 use alloc::vec;
@@ -26,6 +27,7 @@ use crate::dimension::broadcast::co_broadcast;
 use crate::dimension::reshape_dim;
 use crate::error::{self, ErrorKind, ShapeError, from_kind};
 use crate::math_cell::MathCell;
+use crate::zip::FoldWhile;
 use crate::itertools::zip;
 use crate::AxisDescription;
 use crate::order::Order;
@@ -221,6 +223,45 @@ where
         }
     }
 
+    /// Return an uniquely owned copy of the array, in a contiguous memory layout of the given
+    /// `order`.
+    ///
+    /// Unlike [`.to_owned()`](Self::to_owned), which preserves the memory layout when it can,
+    /// the result always has standard strides for the requested order: row major (C) or column
+    /// major (F).
+    ///
+    /// ```
+    /// use ndarray::{array, Order};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    ///
+    /// let f = a.to_owned_order(Order::ColumnMajor);
+    /// assert_eq!(f, a);
+    /// assert_eq!(f.strides(), &[1, 2]);
+    /// assert_eq!(f.as_slice_memory_order(), Some(&[1, 4, 2, 5, 3, 6][..]));
+    ///
+    /// let c = f.to_owned_order(Order::RowMajor);
+    /// assert_eq!(c.strides(), &[3, 1]);
+    /// ```
+    pub fn to_owned_order(&self, order: Order) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let (shape, view) = match order {
+            Order::RowMajor => (self.dim.clone().set_f(false), self.view()),
+            Order::ColumnMajor => (self.dim.clone().set_f(true), self.t()),
+        };
+        // `view` is traversed in logical order, which is the memory order of the result
+        let v = crate::iterators::to_vec_mapped(view.iter(), A::clone);
+        unsafe {
+            // Safe because the shape and element type are from the existing array
+            // and the strides are the default strides for the order.
+            Array::from_shape_vec_unchecked(shape, v)
+        }
+    }
+
     /// Return a shared ownership (copy on write) array, cloning the array
     /// elements if necessary.
     pub fn to_shared(&self) -> ArcArray<A, D>
@@ -282,6 +323,36 @@ where
         }
     }
 
+    /// Return a new handle to the same data, in O(1) time.
+    ///
+    /// This is the same as `.clone()`, but only available for storage that is
+    /// shared: for an `ArcArray` it increments the reference count, and for an
+    /// `ArrayView` it copies the view. The elements are never copied. Unlike
+    /// `.clone()`, it fails to compile for an `Array`, whose clone would copy
+    /// all the elements, so it can be used to make sure that code that intends
+    /// to share data does not copy it by accident.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]].into_shared();
+    /// let b = a.shallow_clone();
+    /// assert_eq!(a.as_ptr(), b.as_ptr());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let b = a.shallow_clone();
+    /// ```
+    pub fn shallow_clone(&self) -> Self
+    where
+        S: DataShared,
+    {
+        self.clone()
+    }
+
     /// Returns a reference to the first element of the array, or `None` if it
     /// is empty.
     ///
@@ -408,6 +479,72 @@ where
         self.view().into_iter_()
     }
 
+    /// Return an iterator of references to the elements of the array, in
+    /// *logical order*.
+    ///
+    /// This is the same as [`.iter()`](Self::iter), with a name that makes the
+    /// order explicit: elements are visited by ascending multidimensional index,
+    /// where the rightmost index is varying the fastest, regardless of the memory
+    /// layout of the array. Use it where the result depends on the order, such as
+    /// for order-sensitive reductions, and see [`.iter_memory()`](Self::iter_memory)
+    /// for the order that is fastest to traverse.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// let t = a.t();
+    /// assert_eq!(t.iter_logical().copied().collect::<Vec<_>>(), [1, 3, 2, 4]);
+    /// ```
+    ///
+    /// Iterator element type is `&A`.
+    pub fn iter_logical(&self) -> Iter<'_, A, D>
+    where
+        S: Data,
+    {
+        self.iter()
+    }
+
+    /// Return an iterator of references to the elements of the array, in
+    /// *memory order*.
+    ///
+    /// Elements are visited in the order they are stored in memory, by
+    /// ascending address, which is the most cache friendly order. For an array
+    /// in standard layout this is the logical order, while for example the
+    /// transpose of such an array is visited column by column. If the array is
+    /// not contiguous, the axes are still traversed so that the axis with the
+    /// smallest stride varies the fastest.
+    ///
+    /// The order is only meaningful together with the layout of the array; use
+    /// [`.iter_logical()`](Self::iter_logical) where the result depends on the
+    /// order.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// let t = a.t();
+    /// assert_eq!(t.iter_memory().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    ///
+    /// let r = a.slice(s![.., ..;-1]);
+    /// assert_eq!(r.iter_memory().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// Iterator element type is `&A`.
+    pub fn iter_memory(&self) -> Iter<'_, A, D>
+    where
+        S: Data,
+    {
+        let mut view = self.view();
+        for ax in 0..view.ndim() {
+            if view.stride_of(Axis(ax)) < 0 {
+                view.invert_axis(Axis(ax));
+            }
+        }
+        crate::impl_owned_array::sort_axes_in_default_order(&mut view);
+        view.into_iter_()
+    }
+
     /// Return an iterator of mutable references to the elements of the array.
     ///
     /// Elements are visited in the *logical order* of the array, which
@@ -1028,6 +1165,43 @@ where
         }
     }
 
+    /// Along `axis`, select the subviews where `mask` is `true` and copy them
+    /// into a new array.
+    ///
+    /// The length of `axis` in the result is the number of `true` elements in
+    /// `mask`; if there are none, the result is empty along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of `mask` is not
+    /// equal to the length of `axis`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let x = arr2(&[[0., 1.],
+    ///                [2., 3.],
+    ///                [4., 5.]]);
+    ///
+    /// let r = x.filter_axis(Axis(0), &[true, false, true]);
+    /// assert_eq!(r, arr2(&[[0., 1.],
+    ///                      [4., 5.]]));
+    ///
+    /// let r = x.filter_axis(Axis(1), &[false, false]);
+    /// assert_eq!(r.shape(), &[3, 0]);
+    /// ```
+    pub fn filter_axis(&self, axis: Axis, mask: &[bool]) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let axis_len = self.len_of(axis);
+        if mask.len() != axis_len {
+            panic!("ndarray: mask of len {} does not match axis of len {}", mask.len(), axis_len);
+        }
+        let indices: Vec<Ix> = (0..axis_len).filter(|&i| mask[i]).collect();
+        self.select(axis, &indices)
+    }
+
     /// Return a producer and iterable that traverses over the *generalized*
     /// rows of the array. For a 2D array these are the regular rows.
     ///
@@ -1216,6 +1390,16 @@ where
     /// This is equivalent to `.axis_iter(Axis(0))`.
     ///
     /// Iterator element is `ArrayView<A, D::Smaller>` (read-only array view).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // A batch of two 2 × 2 matrices
+    /// let batch = array![[[1, 2], [3, 4]],
+    ///                    [[5, 6], [7, 8]]];
+    /// let traces: Vec<i32> = batch.outer_iter().map(|m| m.diag().sum()).collect();
+    /// assert_eq!(traces, [5, 13]);
+    /// ```
     #[allow(deprecated)]
     pub fn outer_iter(&self) -> AxisIter<'_, A, D::Smaller>
     where
@@ -1231,6 +1415,22 @@ where
     /// This is equivalent to `.axis_iter_mut(Axis(0))`.
     ///
     /// Iterator element is `ArrayViewMut<A, D::Smaller>` (read-write array view).
+    /// The subviews are disjoint: each one covers a different index along
+    /// the outermost axis, so they can all be held and mutated independently,
+    /// for example by collecting them or by sending them to other threads.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2], [3, 4], [5, 6]];
+    /// let mut rows: Vec<_> = a.outer_iter_mut().collect();
+    /// let (first, rest) = rows.split_first_mut().unwrap();
+    /// for row in rest {
+    ///     *row += &*first;
+    /// }
+    /// first.fill(0);
+    /// assert_eq!(a, array![[0, 0], [4, 6], [6, 8]]);
+    /// ```
     #[allow(deprecated)]
     pub fn outer_iter_mut(&mut self) -> AxisIterMut<'_, A, D::Smaller>
     where
@@ -1383,6 +1583,39 @@ where
         ExactChunksMut::new(self.view_mut(), chunk_size)
     }
 
+    /// Reduce each whole chunk of shape `chunk_size` to a single value, and
+    /// return an array of the results.
+    ///
+    /// The chunks are those of [`.exact_chunks()`](Self::exact_chunks): they do
+    /// not overlap, and the remainder along each axis that doesn't fit evenly is
+    /// skipped. So the result has length `self.len_of(axis) / chunk_size[axis]`
+    /// (rounded down) along each axis, and the element at index `i` is `f`
+    /// applied to the chunk starting at `i * chunk_size` of `self`.
+    ///
+    /// **Panics** if any dimension of `chunk_size` is zero<br>
+    /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
+    /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3., 4., 5.],
+    ///                [5., 6., 7., 8., 9.],
+    ///                [0., 0., 1., 1., 2.]];
+    ///
+    /// // 2 × 2 average pooling; the last row and column are skipped
+    /// let pooled = a.chunk_reduce((2, 2), |chunk| chunk.mean().unwrap());
+    /// assert_eq!(pooled, array![[3.5, 5.5]]);
+    /// ```
+    pub fn chunk_reduce<E, B, F>(&self, chunk_size: E, f: F) -> Array<B, D>
+    where
+        E: IntoDimension<Dim = D>,
+        F: FnMut(ArrayView<'_, A, D>) -> B,
+        S: Data,
+    {
+        Zip::from(self.exact_chunks(chunk_size)).map_collect(f)
+    }
+
     /// Return a window producer and iterable.
     ///
     /// The windows are all distinct overlapping views of size `window_size`
@@ -1427,6 +1660,41 @@ where
         Windows::new(self.view(), window_size)
     }
 
+    /// Return a window producer and iterable, with a step between windows.
+    ///
+    /// The windows are views of size `window_size` like those of
+    /// [`.windows()`](Self::windows), but along each axis they start at every
+    /// `stride[axis]`-th position, so with a stride of 1 this is the same as
+    /// `.windows()`, and with a stride equal to the window size the windows are
+    /// the same as the chunks of [`.exact_chunks()`](Self::exact_chunks).
+    ///
+    /// Along each axis the number of windows is
+    /// `(len - window_size) / stride + 1` (rounded down) if the window fits, and
+    /// zero otherwise.
+    ///
+    /// **Panics** if any dimension of `window_size` or `stride` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` or `stride` does not match
+    /// the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = Array2::from_shape_fn((4, 5), |(i, j)| 10 * i + j);
+    /// let mut windows = a.windows_with_stride((2, 2), (2, 3)).into_iter();
+    /// assert_eq!(windows.next().unwrap(), array![[0, 1], [10, 11]]);
+    /// assert_eq!(windows.next().unwrap(), array![[3, 4], [13, 14]]);
+    /// assert_eq!(windows.next().unwrap(), array![[20, 21], [30, 31]]);
+    /// assert_eq!(windows.next().unwrap(), array![[23, 24], [33, 34]]);
+    /// assert!(windows.next().is_none());
+    /// ```
+    pub fn windows_with_stride<E>(&self, window_size: E, stride: E) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Windows::new_with_stride(self.view(), window_size, stride)
+    }
+
     /// Returns a producer which traverses over all windows of a given length along an axis.
     ///
     /// The windows are all distinct, possibly-overlapping views. The shape of each window
@@ -1448,6 +1716,20 @@ where
     ///     assert_eq!(window.shape(), &[4, 3, 2]);
     /// }
     /// ```
+    ///
+    /// Windows of two slices give the pairs of adjacent slices along the axis,
+    /// for example for finite differences:
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2.], [4., 8.], [9., 18.]];
+    /// let diffs: Vec<_> = a.axis_windows(Axis(0), 2)
+    ///     .into_iter()
+    ///     .map(|w| &w.row(1) - &w.row(0))
+    ///     .collect();
+    /// assert_eq!(diffs, [array![3., 6.], array![5., 10.]]);
+    /// ```
     pub fn axis_windows(&self, axis: Axis, window_size: usize) -> Windows<'_, A, D>
     where
         S: Data,
@@ -1483,6 +1765,17 @@ where
     ///
     /// The diagonal is simply the sequence indexed by *(0, 0, .., 0)*,
     /// *(1, 1, ..., 1)* etc as long as all axes have elements.
+    ///
+    /// This works for arrays of any dimensionality; the length of the diagonal is the
+    /// shortest axis length, and no data is copied.
+    ///
+    /// ```
+    /// use ndarray::{array, Array3};
+    ///
+    /// let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    /// assert_eq!(a.diag(), array![0, 111]);
+    /// ```
+    #[doc(alias = "diagonal")]
     pub fn diag(&self) -> ArrayView1<'_, A>
     where
         S: Data,
@@ -1538,12 +1831,38 @@ where
     ///
     /// Return `false` otherwise, i.e. the array is possibly not
     /// contiguous in memory, it has custom strides, etc.
+    ///
+    /// The strides of axes of length 1 do not matter, and an array with no
+    /// elements is always in standard layout.
     pub fn is_standard_layout(&self) -> bool {
         dimension::is_layout_c(&self.dim, &self.strides)
     }
 
-    /// Return true if the array is known to be contiguous.
-    pub(crate) fn is_contiguous(&self) -> bool {
+    /// Return `true` if the array data is known to occupy a single contiguous
+    /// block of memory, without holes.
+    ///
+    /// This is the case when the axes, sorted by the absolute value of their
+    /// strides, have the strides of a contiguous C order array. It includes
+    /// both C and F (Fortran) order, as well as any other permutation of the
+    /// axes and axes with negative strides. As in `.is_standard_layout()`, the
+    /// strides of axes of length 1 do not matter.
+    ///
+    /// When this returns `true`, [`.as_slice_memory_order()`](Self::as_slice_memory_order)
+    /// returns the elements as a slice.
+    ///
+    /// ```
+    /// use ndarray::{Array, s};
+    ///
+    /// let a = Array::<f64, _>::zeros((3, 4, 5));
+    /// assert!(a.is_contiguous());
+    /// assert!(a.t().is_contiguous());
+    /// assert!(a.slice(s![..;-1, .., ..]).is_contiguous());
+    /// assert!(a.slice(s![1, .., ..]).is_contiguous());
+    ///
+    /// assert!(!a.slice(s![.., 1, ..]).is_contiguous());
+    /// assert!(!a.slice(s![.., .., ..;2]).is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
         D::is_contiguous(&self.dim, &self.strides)
     }
 
@@ -1832,6 +2151,69 @@ where
         }
     }
 
+    /// Flatten the array to a one-dimensional array, with the elements in logical
+    /// (row major) order.
+    ///
+    /// The array is returned as a `CowArray`; a view if possible, otherwise an owned array.
+    /// This is equivalent to `.to_shape(self.len())`.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    ///
+    /// let flat = a.flatten();
+    /// assert_eq!(flat, array![1, 2, 3, 4, 5, 6]);
+    /// assert!(flat.is_view());
+    ///
+    /// let sliced = a.slice(s![.., ..;2]);
+    /// let flat = sliced.flatten();
+    /// assert_eq!(flat, array![1, 3, 4, 6]);
+    /// assert!(flat.is_owned());
+    /// ```
+    pub fn flatten(&self) -> CowArray<'_, A, Ix1>
+    where
+        A: Clone,
+        S: Data,
+    {
+        // the element count always matches, so this can not fail
+        self.to_shape_order(Ix1(self.len()), Order::RowMajor).unwrap()
+    }
+
+    /// Flatten the array into a one-dimensional owned array, with the elements in
+    /// logical (row major) order.
+    ///
+    /// If the array is an owned array in standard layout, its data is reused
+    /// without copying; otherwise the elements are cloned into a new array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.into_flat(), array![1, 2, 3, 4, 5, 6]);
+    ///
+    /// let b = array![[1, 2, 3],
+    ///                [4, 5, 6]].reversed_axes();
+    /// assert_eq!(b.into_flat(), array![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn into_flat(self) -> Array<A, Ix1>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let len = self.len();
+        if self.is_standard_layout() {
+            // safe because the array is contiguous and the length is unchanged
+            unsafe {
+                self.into_owned().with_strides_dim(Ix1(1), Ix1(len))
+            }
+        } else {
+            self.iter().cloned().collect()
+        }
+    }
+
     /// Transform the array into `shape`; any shape with the same number of
     /// elements is accepted, but the source array or view must be in standard
     /// or column-major (Fortran) layout.
@@ -2069,10 +2451,31 @@ where
     /// For two arrays or views, find their common shape if possible and
     /// broadcast them as array views into that shape.
     ///
+    /// The common shape follows the same rules as the arithmetic operators (and NumPy):
+    /// the shapes are aligned at their last axes, and an axis of length 1 is repeated
+    /// to match the other operand. Broadcast axes have stride zero in the returned views.
+    /// The dimension of the views is the larger of `D` and `E`, which is
+    /// `IxDyn` if either is dynamic.
+    ///
     /// Return `ShapeError` if their shapes can not be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// let b = array![10, 20, 30];
+    ///
+    /// let (va, vb) = a.broadcast_with(&b).unwrap();
+    /// assert_eq!(va, a);
+    /// assert_eq!(vb, array![[10, 20, 30], [10, 20, 30]]);
+    /// assert_eq!(vb.strides(), &[0, 1]);
+    ///
+    /// assert!(a.broadcast_with(&Array2::<i32>::zeros((3, 3))).is_err());
+    /// ```
     #[allow(clippy::type_complexity)]
-    pub(crate) fn broadcast_with<'a, 'b, B, S2, E>(&'a self, other: &'b ArrayBase<S2, E>) ->
-        Result<(ArrayView<'a, A, DimMaxOf<D, E>>, ArrayView<'b, B, DimMaxOf<D, E>>), ShapeError>
+    pub fn broadcast_with<'a, 'b, B, S2, E>(&'a self, other: &'b ArrayBase<S2, E>) ->
+        Result<(ArrayView<'a, A, <D as DimMax<E>>::Output>, ArrayView<'b, B, <D as DimMax<E>>::Output>), ShapeError>
     where
         S: Data<Elem=A>,
         S2: Data<Elem=B>,
@@ -2174,6 +2577,18 @@ where
     ///
     /// Transposition reverses the order of the axes (dimensions and strides)
     /// while retaining the same data.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::<f64, _>::zeros((2, 3, 4));
+    /// let b = a.reversed_axes();
+    /// assert_eq!(b.shape(), &[4, 3, 2]);
+    /// assert_eq!(b.strides(), &[1, 4, 12]);
+    ///
+    /// let c = b.reversed_axes();
+    /// assert!(c.is_standard_layout());
+    /// ```
     pub fn reversed_axes(mut self) -> ArrayBase<S, D> {
         self.dim.slice_mut().reverse();
         self.strides.slice_mut().reverse();
@@ -2351,6 +2766,43 @@ where
         self.map_inplace(move |elt| *elt = x.clone());
     }
 
+    /// Perform an elementwise assigment to `self` from element `x`, where the
+    /// corresponding element of `mask` is `true`.
+    ///
+    /// Elements where `mask` is `false` are left unchanged.
+    ///
+    /// **Panics** if the shapes of `self` and `mask` are not equal.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1., f64::NAN],
+    ///                    [f64::NAN, 4.]]);
+    /// let mask = a.mapv(f64::is_nan);
+    ///
+    /// a.assign_scalar_where(&mask, 0.);
+    /// assert_eq!(a, arr2(&[[1., 0.],
+    ///                      [0., 4.]]));
+    /// ```
+    pub fn assign_scalar_where<S2>(&mut self, mask: &ArrayBase<S2, D>, x: A)
+    where
+        S: DataMut,
+        S2: Data<Elem = bool>,
+        A: Clone,
+    {
+        assert!(
+            self.shape() == mask.shape(),
+            "ndarray: mask of shape {:?} does not match array of shape {:?}",
+            mask.shape(),
+            self.shape()
+        );
+        Zip::from(self).and(mask).for_each(move |elt, &selected| {
+            if selected {
+                *elt = x.clone();
+            }
+        });
+    }
+
     pub(crate) fn zip_mut_with_same_shape<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, mut f: F)
     where
         S: DataMut,
@@ -2409,6 +2861,24 @@ where
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
     /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1, 2],
+    ///                    [3, 4]]);
+    /// let b = arr2(&[[5, 6],
+    ///                [7, 8]]);
+    ///
+    /// a.zip_mut_with(&b, |x, &y| *x = *x * 10 + y);
+    /// assert_eq!(a, arr2(&[[15, 26],
+    ///                      [37, 48]]));
+    ///
+    /// // `rhs` is broadcast if needed, here along the rows
+    /// a.zip_mut_with(&b.row(0), |x, &y| *x -= y);
+    /// assert_eq!(a, arr2(&[[10, 20],
+    ///                      [32, 42]]));
+    /// ```
     #[inline]
     pub fn zip_mut_with<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F)
     where
@@ -2447,10 +2917,50 @@ where
         }
     }
 
+    /// Traverse the array elements and apply a fold while the return value of
+    /// `f` is `FoldWhile::Continue`, stopping at the first `FoldWhile::Done`.
+    ///
+    /// Elements are visited in logical order: by increasing index, with the
+    /// last axis varying the fastest. Return `FoldWhile::Done` if the fold was
+    /// stopped early, and `FoldWhile::Continue` if all elements were visited;
+    /// use `.into_inner()` to get the accumulated value in either case.
+    ///
+    /// ```
+    /// use ndarray::{array, FoldWhile};
+    ///
+    /// // Find the running sum when it first exceeds a threshold
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let result = a.fold_while(0, |acc, &x| {
+    ///     if acc + x > 8 {
+    ///         FoldWhile::Done(acc + x)
+    ///     } else {
+    ///         FoldWhile::Continue(acc + x)
+    ///     }
+    /// });
+    /// assert!(result.is_done());
+    /// assert_eq!(result.into_inner(), 10);
+    /// ```
+    pub fn fold_while<'a, F, B>(&'a self, init: B, mut f: F) -> FoldWhile<B>
+    where
+        F: FnMut(B, &'a A) -> FoldWhile<B>,
+        A: 'a,
+        S: Data,
+    {
+        let mut acc = init;
+        for elt in self.iter() {
+            match f(acc, elt) {
+                FoldWhile::Continue(next) => acc = next,
+                done @ FoldWhile::Done(_) => return done,
+            }
+        }
+        FoldWhile::Continue(acc)
+    }
+
     /// Call `f` by reference on each element and create a new array
     /// with the new values.
     ///
-    /// Elements are visited in arbitrary order.
+    /// Elements are visited in arbitrary order; use
+    /// [`.map_ordered()`](Self::map_ordered) to visit them in logical order.
     ///
     /// Return an array with the same shape as `self`.
     ///
@@ -2482,6 +2992,72 @@ where
         }
     }
 
+    /// Call `f` by reference on each element, in logical order, and create a
+    /// new array with the new values.
+    ///
+    /// Unlike [`.map()`](Self::map), which visits the elements in arbitrary
+    /// order, the elements are visited in logical (row major) order, the same
+    /// order as `.iter()`: the last index is the most rapidly varying. This
+    /// matters when `f` has side effects, like counting or accumulating.
+    ///
+    /// Return an array with the same shape as `self`, in standard layout.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// let mut count = 0;
+    /// let b = a.t().map_ordered(|&x| {
+    ///     count += 1;
+    ///     x * 10 + count
+    /// });
+    /// assert_eq!(b, arr2(&[[11, 32],
+    ///                      [23, 44]]));
+    /// ```
+    pub fn map_ordered<'a, B, F>(&'a self, f: F) -> Array<B, D>
+    where
+        F: FnMut(&'a A) -> B,
+        A: 'a,
+        S: Data,
+    {
+        unsafe {
+            ArrayBase::from_shape_trusted_iter_unchecked(self.dim.clone(), self.iter(), f)
+        }
+    }
+
+    /// Call `f` with the index and a reference to each element, and create a
+    /// new array with the new values.
+    ///
+    /// The index is passed in the pattern form of the dimension, for example
+    /// `(i, j)` for a two-dimensional array, like `.indexed_iter()`.
+    ///
+    /// Elements are visited in logical (row major) order, the same order as
+    /// `.indexed_iter()`.
+    ///
+    /// Return an array with the same shape as `self`, in standard layout.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// let b = a.map_indexed(|(i, _j), &x| x * i as f64);
+    /// assert_eq!(b, arr2(&[[0., 0.],
+    ///                      [3., 4.]]));
+    /// ```
+    pub fn map_indexed<'a, B, F>(&'a self, mut f: F) -> Array<B, D>
+    where
+        F: FnMut(D::Pattern, &'a A) -> B,
+        A: 'a,
+        S: Data,
+    {
+        unsafe {
+            ArrayBase::from_shape_trusted_iter_unchecked(self.dim.clone(), self.indexed_iter(),
+                                                         move |(index, elt)| f(index, elt))
+        }
+    }
+
     /// Call `f` on a mutable reference of each element and create a new array
     /// with the new values.
     ///
@@ -2531,6 +3107,57 @@ where
         self.map(move |x| f(x.clone()))
     }
 
+    /// Return a new array with the elements converted to `B` using `From`.
+    ///
+    /// Conversions through `From` are lossless, for example `i32` to `f64` or
+    /// `u8` to `u32`; for conversions that may lose information, like `f64` to
+    /// `f32` or `i64` to `f64`, see [`.convert_lossy()`](Self::convert_lossy).
+    ///
+    /// (Not to be confused with the `cast` methods of raw views, which
+    /// reinterpret the memory of the elements instead.)
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2], [3, 4]];
+    /// let b = a.convert::<f64>();
+    /// assert_eq!(b, array![[1., 2.], [3., 4.]]);
+    /// ```
+    #[doc(alias = "cast")]
+    pub fn convert<B>(&self) -> Array<B, D>
+    where
+        A: Clone,
+        B: From<A>,
+        S: Data,
+    {
+        self.mapv(B::from)
+    }
+
+    /// Return a new array with the elements converted to `B` like with the
+    /// `as` operator, which is possibly lossy.
+    ///
+    /// This is implemented for the primitive numeric types (through
+    /// `num_traits::AsPrimitive`), and has the same semantics as `as`:
+    /// floats are rounded to the nearest representable value, conversions from
+    /// float to integer truncate towards zero and saturate (NaN becomes 0), and
+    /// conversions between integers wrap around or truncate.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-1.5, 0.5, 300.];
+    /// assert_eq!(a.convert_lossy::<i32>(), array![-1, 0, 300]);
+    /// assert_eq!(a.convert_lossy::<u8>(), array![0, 0, 255]);
+    /// ```
+    pub fn convert_lossy<B>(&self) -> Array<B, D>
+    where
+        A: num_traits::AsPrimitive<B>,
+        B: Copy + 'static,
+        S: Data,
+    {
+        self.mapv(|x| x.as_())
+    }
+
     /// Call `f` by **v**alue on each element, update the array with the new values
     /// and return it.
     ///
@@ -2713,6 +3340,42 @@ where
         }
     }
 
+    /// Reduce each lane along `axis` to a single value with `reduce`, producing
+    /// a new array with one less dimension.
+    ///
+    /// This is the general building block for reductions along an axis like
+    /// [`.sum_axis()`](ArrayBase::sum_axis) and
+    /// [`.mean_axis()`](ArrayBase::mean_axis); it is the same as
+    /// [`.map_axis()`](ArrayBase::map_axis). `reduce` is called once for each
+    /// lane, in arbitrary order. If `axis` has length zero, it is called with
+    /// empty lanes; if another axis has length zero, the result is empty and
+    /// `reduce` is not called.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 2], [0, 5, 4]];
+    /// // the index of the greatest element of each row
+    /// let argmax = a.reduce_axis(Axis(1), |row| {
+    ///     row.indexed_iter().max_by_key(|&(_, x)| x).map(|(i, _)| i)
+    /// });
+    /// assert_eq!(argmax, array![Some(0), Some(1)]);
+    ///
+    /// let e = a.slice(ndarray::s![.., ..0]);
+    /// assert_eq!(e.reduce_axis(Axis(1), |row| row.len()), array![0, 0]);
+    /// ```
+    pub fn reduce_axis<'a, B, F>(&'a self, axis: Axis, reduce: F) -> Array<B, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: FnMut(ArrayView1<'a, A>) -> B,
+        A: 'a,
+        S: Data,
+    {
+        self.map_axis(axis, reduce)
+    }
+
     /// Reduce the values along an axis into just one value, producing a new
     /// array with one less dimension.
     /// 1-dimensional lanes are passed as mutable references to the reducer,
@@ -2748,6 +3411,292 @@ where
         }
     }
 
+    /// For each lane along `axis`, find the index of the first element for
+    /// which `predicate` returns `true`, producing a new array with one less
+    /// dimension.
+    ///
+    /// Each lane is scanned from index 0 upwards, and the scan stops at the
+    /// first match. The result is `None` for lanes where no element matches.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[0.1, 0.4, 0.7, 0.9],
+    ///                [0.2, 0.3, 0.3, 0.2],
+    ///                [0.6, 0.8, 0.2, 0.1]];
+    ///
+    /// // first crossing of 0.5 in each row
+    /// let crossing = a.first_index_axis(Axis(1), |&x| x > 0.5);
+    /// assert_eq!(crossing, array![Some(2), None, Some(0)]);
+    /// ```
+    pub fn first_index_axis<F>(&self, axis: Axis, mut predicate: F) -> Array<Option<usize>, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: FnMut(&A) -> bool,
+        S: Data,
+    {
+        self.map_axis(axis, |lane| lane.iter().position(|elt| predicate(elt)))
+    }
+
+    /// Apply `f` to each lane along `axis`, and collect the returned arrays
+    /// along the same axis of a new array.
+    ///
+    /// Each call to `f` may return an array of any length `m`, as long as it
+    /// is the same for all lanes; the result then has the shape of `self`
+    /// except that `axis` has length `m`. (If there are no lanes, `m` is 0.)
+    ///
+    /// Lanes are visited in logical order of the remaining axes.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `f` returns arrays of
+    /// different lengths.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    ///
+    /// // each column becomes its sum followed by its product
+    /// let b = a.apply_along_axis(Axis(0), |col| array![col.sum(), col.product()]);
+    /// assert_eq!(b, array![[5, 7, 9],
+    ///                      [4, 10, 18]]);
+    ///
+    /// // each row becomes its cumulative sum, without the first element
+    /// let c = a.apply_along_axis(Axis(1), |row| {
+    ///     let mut acc = row.to_owned();
+    ///     acc.accumulate_axis_inplace(Axis(0), |&prev, curr| *curr += prev);
+    ///     acc.slice_move(ndarray::s![1..])
+    /// });
+    /// assert_eq!(c, array![[3, 6],
+    ///                      [9, 15]]);
+    /// ```
+    pub fn apply_along_axis<B, F>(&self, axis: Axis, mut f: F) -> Array<B, D>
+    where
+        D: RemoveAxis,
+        F: FnMut(ArrayView1<'_, A>) -> Array1<B>,
+        S: Data,
+    {
+        let mut outputs = Vec::with_capacity(self.len() / usize::max(self.len_of(axis), 1));
+        for lane in self.lanes(axis) {
+            outputs.push(f(lane));
+        }
+        let out_len = outputs.first().map_or(0, |out| out.len());
+        if let Some(out) = outputs.iter().find(|out| out.len() != out_len) {
+            panic!("ndarray: apply_along_axis: function returned arrays of different lengths {} and {}",
+                   out_len, out.len());
+        }
+
+        let mut dim = self.raw_dim();
+        dim.set_axis(axis, out_len);
+        let mut result = Array::uninit(dim);
+        for (out, dst) in outputs.into_iter().zip(result.lanes_mut(axis)) {
+            out.move_into_uninit(dst);
+        }
+        unsafe {
+            // every lane has been written, and the lanes cover the result
+            result.assume_init()
+        }
+    }
+
+    /// Return the indices that would sort each lane along `axis`.
+    ///
+    /// The result has the same shape as `self`, and each lane along `axis` is
+    /// the permutation of `0..n` that puts the corresponding lane of `self` in
+    /// ascending order: `lane[perm[0]] <= lane[perm[1]] <= ...`. This is
+    /// NumPy's `argsort(axis=...)`.
+    ///
+    /// The sort is stable, so equal elements keep the order of their indices.
+    /// Elements that are not comparable to themselves, such as NaN, are placed
+    /// after all other elements. See [`.argsort_axis_by()`](Self::argsort_axis_by)
+    /// to use a custom comparison.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3., 1., 2.],
+    ///                [0., f64::NAN, -1.]];
+    /// assert_eq!(a.argsort_axis(Axis(1)), array![[1, 2, 0],
+    ///                                            [2, 0, 1]]);
+    /// assert_eq!(a.argsort_axis(Axis(0)), array![[1, 0, 1],
+    ///                                            [0, 1, 0]]);
+    /// ```
+    pub fn argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+        S: Data,
+    {
+        self.argsort_axis_by(axis, |a, b| {
+            a.partial_cmp(b).unwrap_or_else(|| {
+                // order elements that are not comparable to themselves last
+                let a_nan = a.partial_cmp(a).is_none();
+                let b_nan = b.partial_cmp(b).is_none();
+                a_nan.cmp(&b_nan)
+            })
+        })
+    }
+
+    /// Return the indices that would sort each lane along `axis`, using the
+    /// comparison function `compare`.
+    ///
+    /// See [`.argsort_axis()`](Self::argsort_axis); the sort is stable, and
+    /// `compare` must implement a total order.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![["b", "a"], ["a", "c"]];
+    /// // descending order
+    /// assert_eq!(a.argsort_axis_by(Axis(1), |x, y| y.cmp(x)), array![[0, 1], [1, 0]]);
+    /// ```
+    pub fn argsort_axis_by<F>(&self, axis: Axis, mut compare: F) -> Array<usize, D>
+    where
+        D: RemoveAxis,
+        F: FnMut(&A, &A) -> Ordering,
+        S: Data,
+    {
+        let n = self.len_of(axis);
+        let mut result = Array::zeros(self.raw_dim());
+        // the permutation of each lane is sorted in this buffer, which works for
+        // lanes with any stride
+        let mut perm = Vec::with_capacity(n);
+        Zip::from(self.lanes(axis))
+            .and(result.lanes_mut(axis))
+            .for_each(|lane, mut out| {
+                perm.clear();
+                perm.extend(0..n);
+                perm.sort_by(|&i, &j| compare(&lane[i], &lane[j]));
+                for (dst, &index) in out.iter_mut().zip(&perm) {
+                    *dst = index;
+                }
+            });
+        result
+    }
+
+    /// Gather elements along `axis` by the indices in `indices`.
+    ///
+    /// The result has the shape of `indices`, and its element at each position
+    /// is the element of `self` at the same position, except that the index
+    /// along `axis` is replaced by the value of `indices` there. In other
+    /// words, each lane of the result along `axis` is the corresponding lane of
+    /// `self` indexed by the corresponding lane of `indices`. This is NumPy's
+    /// `take_along_axis`, and it can for example apply a permutation computed
+    /// by [`.argsort_axis()`](Self::argsort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds, if the shape of `indices` differs
+    /// from the shape of `self` on any axis other than `axis`, or if any index
+    /// is out of bounds for the length of `self` along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[30, 10, 20],
+    ///                [5, 6, 4]];
+    /// let order = a.argsort_axis(Axis(1));
+    /// assert_eq!(a.take_along_axis(Axis(1), &order), array![[10, 20, 30],
+    ///                                                        [4, 5, 6]]);
+    ///
+    /// // the index array can have a different length along the axis
+    /// let first_and_last = array![[0, 2], [0, 2]];
+    /// assert_eq!(a.take_along_axis(Axis(1), &first_and_last), array![[30, 20],
+    ///                                                                 [5, 4]]);
+    /// ```
+    pub fn take_along_axis<S2>(&self, axis: Axis, indices: &ArrayBase<S2, D>) -> Array<A, D>
+    where
+        A: Clone,
+        D: RemoveAxis,
+        S: Data,
+        S2: Data<Elem = usize>,
+    {
+        assert_lanes_compatible("take_along_axis", self.shape(), indices.shape(), axis);
+        let len = self.len_of(axis);
+        let mut result = Array::uninit(indices.raw_dim());
+        Zip::from(result.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .and(indices.lanes(axis))
+            .for_each(|mut out, lane, lane_indices| {
+                for (dst, &index) in out.iter_mut().zip(&lane_indices) {
+                    let elt = lane.get(index).unwrap_or_else(|| {
+                        panic!("ndarray: take_along_axis: index {} is out of bounds for axis {} with length {}",
+                               index, axis.index(), len)
+                    });
+                    dst.assign_elem(elt.clone());
+                }
+            });
+        unsafe {
+            // every lane has been written, and the lanes cover the result
+            result.assume_init()
+        }
+    }
+
+    /// Scatter `values` along `axis` into the positions given by `indices`.
+    ///
+    /// This is the inverse of [`.take_along_axis()`](Self::take_along_axis):
+    /// for each position of `indices`, the element of `self` at the same
+    /// position, except with the index along `axis` replaced by the value of
+    /// `indices` there, is assigned a clone of the element of `values` at that
+    /// position. This is NumPy's `put_along_axis`.
+    ///
+    /// `values` is broadcast to the shape of `indices` if needed, so for example
+    /// an array with all axes of length 1 assigns the same value everywhere. If
+    /// an index appears more than once in a lane of `indices`, the last value in
+    /// that lane wins.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the shape of `indices` differs
+    /// from the shape of `self` on any axis other than `axis`, if `values`
+    /// can't be broadcast to the shape of `indices`, or if any index is out of
+    /// bounds for the length of `self` along `axis`. All of this is checked
+    /// before any element is assigned.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// a.put_along_axis(Axis(1), &array![[2], [0]], &array![[30], [40]]);
+    /// assert_eq!(a, array![[1, 2, 30],
+    ///                      [40, 5, 6]]);
+    ///
+    /// // zero the largest element of each column
+    /// let top = a.argsort_axis(Axis(0)).slice_move(ndarray::s![1.., ..]);
+    /// a.put_along_axis(Axis(0), &top, &array![[0]]);
+    /// assert_eq!(a, array![[1, 2, 0],
+    ///                      [0, 0, 6]]);
+    /// ```
+    pub fn put_along_axis<S2, S3>(&mut self, axis: Axis, indices: &ArrayBase<S2, D>, values: &ArrayBase<S3, D>)
+    where
+        A: Clone,
+        D: RemoveAxis,
+        S: DataMut,
+        S2: Data<Elem = usize>,
+        S3: Data<Elem = A>,
+    {
+        assert_lanes_compatible("put_along_axis", self.shape(), indices.shape(), axis);
+        let values = values.broadcast(indices.raw_dim()).unwrap_or_else(|| {
+            panic!("ndarray: put_along_axis: could not broadcast values of shape {:?} to shape {:?}",
+                   values.shape(), indices.shape())
+        });
+        let len = self.len_of(axis);
+        if let Some(&index) = indices.iter().find(|&&index| index >= len) {
+            panic!("ndarray: put_along_axis: index {} is out of bounds for axis {} with length {}",
+                   index, axis.index(), len);
+        }
+        Zip::from(self.lanes_mut(axis))
+            .and(indices.lanes(axis))
+            .and(values.lanes(axis))
+            .for_each(|mut lane, lane_indices, lane_values| {
+                for (&index, value) in zip(&lane_indices, &lane_values) {
+                    lane[index] = value.clone();
+                }
+            });
+    }
+
     /// Remove the `index`th elements along `axis` and shift down elements from higher indexes.
     ///
     /// Note that this "removes" the elements by swapping them around to the end of the axis and
@@ -2829,6 +3778,18 @@ where
     }
 }
 
+/// **Panics** unless `shape` and `other` have the same number of axes and the
+/// same lengths on all axes except `axis`.
+fn assert_lanes_compatible(method: &str, shape: &[usize], other: &[usize], axis: Axis) {
+    let compatible = shape.len() == other.len()
+        && zip(shape, other)
+            .enumerate()
+            .all(|(ax, (a, b))| ax == axis.index() || a == b);
+    if !compatible {
+        panic!("ndarray: {}: shape {:?} is incompatible with array shape {:?} on axes other than {}",
+               method, other, shape, axis.index());
+    }
+}
 
 /// Transmute from A to B.
 ///
@@ -2843,5 +3804,3 @@ unsafe fn unlimited_transmute<A, B>(data: A) -> B {
     let old_data = ManuallyDrop::new(data);
     (&*old_data as *const A as *const B).read()
 }
-
-type DimMaxOf<A, B> = <A as DimMax<B>>::Output;
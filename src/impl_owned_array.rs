@@ -12,6 +12,7 @@ use crate::error::{ErrorKind, ShapeError};
 use crate::iterators::Baseiter;
 use crate::low_level_util::AbortIfPanic;
 use crate::OwnedRepr;
+use crate::Slice;
 use crate::Zip;
 
 /// Methods specific to `Array0`.
@@ -63,9 +64,82 @@ where
     ///
     /// If the array is in standard memory layout, the logical element order
     /// of the array (`.iter()` order) and of the returned vector will be the same.
+    ///
+    /// The storage of an `Array` is always uniquely held, so this never copies
+    /// and, unlike [`DataOwned::into_vec`], does
+    /// not need `A: Clone`.
     pub fn into_raw_vec(self) -> Vec<A> {
         self.data.into_vec()
     }
+
+    /// Shrink the capacity of the backing storage as much as possible.
+    ///
+    /// Owned arrays can keep more capacity than they need, for example after
+    /// [`.append()`](Self::append) or [`.resize()`](Self::resize). If the array's
+    /// elements are all of the elements of its backing storage, this releases the
+    /// unused capacity (which may move the elements to a new allocation). If the
+    /// array has been sliced so that some elements of the storage are unreachable,
+    /// it does nothing, since those elements can't be removed without moving the
+    /// array's elements; use [`.as_standard_layout()`](ArrayBase::as_standard_layout)
+    /// and `.into_owned()` to get a compact copy in that case.
+    ///
+    /// This method is only available for `Array`; shared arrays such as `ArcArray`
+    /// can be converted with [`.into_owned()`](ArrayBase::into_owned) first.
+    ///
+    /// ```
+    /// use ndarray::{Array, aview1};
+    ///
+    /// let mut a = Array::<f64, _>::zeros((0, 3));
+    /// for i in 0..5 {
+    ///     a.push_row(aview1(&[i as f64; 3])).unwrap();
+    /// }
+    /// a.shrink_to_fit();
+    /// assert_eq!(a.into_raw_vec().capacity(), 15);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if self.len() != self.data.len() {
+            return;
+        }
+        unsafe {
+            // shrink backing storage and update head ptr
+            let data_to_array_offset = if mem::size_of::<A>() != 0 {
+                self.as_ptr().offset_from(self.data.as_ptr())
+            } else {
+                0
+            };
+            debug_assert!(data_to_array_offset >= 0);
+            self.ptr = self.data.shrink_to_fit().offset(data_to_array_offset);
+        }
+        debug_assert!(self.pointer_is_inbounds());
+    }
+}
+
+/// Methods specific to `ArcArray`.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<A, D> ArcArray<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    /// Return a vector of the elements in the array, in the way they are
+    /// stored internally, like [`Array::into_raw_vec`].
+    ///
+    /// If this is the only array that holds its storage, the storage is
+    /// returned without copying; otherwise the elements of the storage are
+    /// cloned into a new vector.
+    ///
+    /// ```
+    /// use ndarray::ArcArray;
+    ///
+    /// let a = ArcArray::from_vec(vec![1, 2, 3]);
+    /// let b = a.clone();
+    /// assert_eq!(a.into_raw_vec(), vec![1, 2, 3]);
+    /// assert_eq!(b.into_raw_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_raw_vec(self) -> Vec<A> {
+        DataOwned::into_vec(self.data)
+    }
 }
 
 /// Methods specific to `Array2`.
@@ -659,6 +733,81 @@ impl<A, D> Array<A, D>
     }
 }
 
+impl<A, D> Array<A, D>
+where
+    D: RemoveAxis,
+{
+    /// Change the length of the array along axis 0 to `new_len`, truncating it or padding it
+    /// with clones of `fill`.
+    ///
+    /// Shrinking slices the array in place. If the array is in standard layout and starts at the
+    /// beginning of its allocation, the removed elements are dropped and the backing storage is
+    /// truncated without reallocating; otherwise the removed elements are kept alive (but
+    /// unreachable) until the array is dropped.
+    ///
+    /// Growing appends along axis 0, see [`.append()`](Self::append); this is efficient if
+    /// axis 0 is the array's growing axis, for example in a C-layout array, and reallocates the
+    /// array into a suitable layout otherwise.
+    ///
+    /// ***Panics*** if the array has zero dimensions or if the new shape would overflow
+    /// `isize`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2],
+    ///                    [3, 4],
+    ///                    [5, 6]];
+    /// a.resize(2, 0);
+    /// assert_eq!(a, array![[1, 2], [3, 4]]);
+    /// a.resize(4, 9);
+    /// assert_eq!(a, array![[1, 2], [3, 4], [9, 9], [9, 9]]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, fill: A)
+    where
+        A: Clone,
+    {
+        assert!(self.ndim() > 0, "ndarray: can not resize a zero-dimensional array");
+        let axis = Axis(0);
+        let len = self.len_of(axis);
+        if new_len <= len {
+            self.slice_axis_inplace(axis, Slice::from(..new_len));
+            if self.is_standard_layout() && self.as_ptr() == self.data.as_ptr() {
+                // the array's elements are exactly the first `self.len()` elements of the data
+                self.data.truncate(self.len());
+            }
+        } else {
+            let mut pad_dim = self.raw_dim();
+            pad_dim.set_axis(axis, new_len - len);
+            let fill = crate::aview0(&fill);
+            self.append(axis, fill.broadcast(pad_dim).unwrap()).unwrap();
+        }
+    }
+
+    /// Return the array with its length along axis 0 changed to `new_len`, truncating it or
+    /// padding it with clones of `fill`.
+    ///
+    /// See [`.resize()`](Self::resize) for when this is done in place.
+    ///
+    /// ***Panics*** if the array has zero dimensions or if the new shape would overflow
+    /// `isize`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 2., 3.];
+    /// assert_eq!(a.clone().resized(2, 0.), array![1., 2.]);
+    /// assert_eq!(a.resized(5, 0.), array![1., 2., 3., 0., 0.]);
+    /// ```
+    pub fn resized(mut self, new_len: usize, fill: A) -> Array<A, D>
+    where
+        A: Clone,
+    {
+        self.resize(new_len, fill);
+        self
+    }
+}
+
 /// This drops all "unreachable" elements in `self_` given the data pointer and data length.
 ///
 /// # Safety
@@ -738,7 +887,7 @@ where
 /// Sort axes to standard order, i.e Axis(0) has biggest stride and Axis(n - 1) least stride
 ///
 /// The axes should have stride >= 0 before calling this method.
-fn sort_axes_in_default_order<S, D>(a: &mut ArrayBase<S, D>)
+pub(crate) fn sort_axes_in_default_order<S, D>(a: &mut ArrayBase<S, D>)
 where
     S: RawData,
     D: Dimension,
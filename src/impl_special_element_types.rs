@@ -6,7 +6,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::mem::MaybeUninit;
+use std::mem::{self, MaybeUninit};
+use std::slice;
+
+use num_complex::Complex;
+use num_traits::Num;
+use std::ops::Neg;
 
 use crate::imp_prelude::*;
 use crate::RawDataSubst;
@@ -41,3 +46,124 @@ where
         ArrayBase::from_data_ptr(data, ptr).with_strides_dim(strides, dim)
     }
 }
+
+/// Methods specific to arrays with `Complex` elements.
+///
+/// `Complex<T>` is `repr(C)` with the fields `re` and `im` in that order, so an
+/// array of complex numbers is, in memory, an array of interleaved real and
+/// imaginary parts. These methods expose that layout without copying.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<T, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = Complex<T>>,
+    D: Dimension,
+{
+    /// Return a view of the real parts of the elements.
+    ///
+    /// The view has the same shape as `self`, and strides that are twice those of
+    /// `self` (in units of `T`).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// assert_eq!(a.re(), array![1., 3.]);
+    /// assert_eq!(a.im(), array![2., 4.]);
+    /// assert_eq!(a.re().strides(), &[2]);
+    /// ```
+    pub fn re(&self) -> ArrayView<'_, T, D> {
+        self.view().split_complex().re
+    }
+
+    /// Return a view of the imaginary parts of the elements.
+    ///
+    /// The view has the same shape as `self`, and strides that are twice those of
+    /// `self` (in units of `T`).
+    pub fn im(&self) -> ArrayView<'_, T, D> {
+        self.view().split_complex().im
+    }
+
+    /// Return the array's data as a slice of interleaved real and imaginary
+    /// parts, if it is contiguous and in standard order.
+    /// Return `None` otherwise.
+    ///
+    /// The slice has twice as many elements as the array: the real and imaginary
+    /// parts of the first element, then of the second element, and so on, which is
+    /// the layout expected by most C and Fortran libraries.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![[Complex64::new(1., 2.), Complex64::new(3., 4.)],
+    ///                [Complex64::new(5., 6.), Complex64::new(7., 8.)]];
+    /// assert_eq!(a.as_real_slice(), Some(&[1., 2., 3., 4., 5., 6., 7., 8.][..]));
+    /// assert_eq!(a.t().as_real_slice(), None);
+    /// ```
+    pub fn as_real_slice(&self) -> Option<&[T]> {
+        // `Complex<T>` is `repr(C)` with exactly the fields `re: T` and `im: T`.
+        debug_assert_eq!(mem::size_of::<Complex<T>>(), 2 * mem::size_of::<T>());
+        self.as_slice().map(|slc| unsafe {
+            slice::from_raw_parts(slc.as_ptr().cast::<T>(), 2 * slc.len())
+        })
+    }
+
+    /// Return an array with the complex conjugates of the elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., -4.)];
+    /// assert_eq!(a.conj(), array![Complex64::new(1., -2.), Complex64::new(3., 4.)]);
+    /// ```
+    pub fn conj(&self) -> Array<Complex<T>, D>
+    where
+        T: Clone + Num + Neg<Output = T>,
+    {
+        self.mapv(|z| z.conj())
+    }
+
+    /// Replace each element by its complex conjugate, in place.
+    pub fn conj_inplace(&mut self)
+    where
+        T: Clone + Num + Neg<Output = T>,
+        S: DataMut,
+    {
+        self.mapv_inplace(|z| z.conj())
+    }
+}
+
+/// Methods specific to two-dimensional arrays with `Complex` elements.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<T, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = Complex<T>>,
+{
+    /// Return the conjugate transpose (Hermitian adjoint) of the matrix.
+    ///
+    /// The result is the transpose of [`.conj()`](ArrayBase::conj); it uses the
+    /// memory layout of the transposed view, so for a matrix in standard layout
+    /// the result is in Fortran layout, and no elements are reordered.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let c = Complex64::new;
+    /// let a = array![[c(1., 1.), c(2., 0.)],
+    ///                [c(0., -3.), c(4., 5.)]];
+    /// assert_eq!(a.conj_transpose(), array![[c(1., -1.), c(0., 3.)],
+    ///                                       [c(2., 0.), c(4., -5.)]]);
+    /// ```
+    #[doc(alias = "adjoint")]
+    pub fn conj_transpose(&self) -> Array2<Complex<T>>
+    where
+        T: Clone + Num + Neg<Output = T>,
+    {
+        self.t().mapv(|z| z.conj())
+    }
+}
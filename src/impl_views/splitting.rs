@@ -94,6 +94,63 @@ where
             (left.deref_into_view(), right.deref_into_view())
         }
     }
+
+    /// Split off the first subview along `axis`, and return it together with a
+    /// view of the remaining subviews.
+    ///
+    /// Returns `None` if the length of `axis` is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```rust
+    /// use ndarray::{aview1, aview2, Axis};
+    ///
+    /// let a = aview2(&[[0, 1, 2],
+    ///                  [3, 4, 5]]);
+    ///
+    /// let (first, rest) = a.split_first_along(Axis(1)).unwrap();
+    /// assert_eq!(first, aview1(&[0, 3]));
+    /// assert_eq!(rest, aview2(&[[1, 2], [4, 5]]));
+    /// ```
+    pub fn split_first_along(self, axis: Axis) -> Option<(ArrayView<'a, A, D::Smaller>, Self)>
+    where
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return None;
+        }
+        let (first, rest) = self.split_at(axis, 1);
+        Some((first.index_axis_move(axis, 0), rest))
+    }
+
+    /// Split off the last subview along `axis`, and return it together with a
+    /// view of the preceding subviews.
+    ///
+    /// Returns `None` if the length of `axis` is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```rust
+    /// use ndarray::{aview1, aview2, Axis};
+    ///
+    /// let a = aview2(&[[0, 1, 2],
+    ///                  [3, 4, 5]]);
+    ///
+    /// let (last, rest) = a.split_last_along(Axis(0)).unwrap();
+    /// assert_eq!(last, aview1(&[3, 4, 5]));
+    /// assert_eq!(rest, aview2(&[[0, 1, 2]]));
+    /// ```
+    pub fn split_last_along(self, axis: Axis) -> Option<(ArrayView<'a, A, D::Smaller>, Self)>
+    where
+        D: RemoveAxis,
+    {
+        let len = self.len_of(axis);
+        if len == 0 {
+            return None;
+        }
+        let (rest, last) = self.split_at(axis, len - 1);
+        Some((last.index_axis_move(axis, 0), rest))
+    }
 }
 
 impl<'a, T, D> ArrayView<'a, Complex<T>, D>
@@ -143,6 +200,41 @@ where
         }
     }
 
+    /// Split off the first subview along `axis`, and return it together with a
+    /// mutable view of the remaining subviews.
+    ///
+    /// Returns `None` if the length of `axis` is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn split_first_along(self, axis: Axis) -> Option<(ArrayViewMut<'a, A, D::Smaller>, Self)>
+    where
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return None;
+        }
+        let (first, rest) = self.split_at(axis, 1);
+        Some((first.index_axis_move(axis, 0), rest))
+    }
+
+    /// Split off the last subview along `axis`, and return it together with a
+    /// mutable view of the preceding subviews.
+    ///
+    /// Returns `None` if the length of `axis` is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn split_last_along(self, axis: Axis) -> Option<(ArrayViewMut<'a, A, D::Smaller>, Self)>
+    where
+        D: RemoveAxis,
+    {
+        let len = self.len_of(axis);
+        if len == 0 {
+            return None;
+        }
+        let (rest, last) = self.split_at(axis, len - 1);
+        Some((last.index_axis_move(axis, 0), rest))
+    }
+
     /// Split the view into multiple disjoint slices.
     ///
     /// This is similar to [`.multi_slice_mut()`], but `.multi_slice_move()`
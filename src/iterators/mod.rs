@@ -1460,6 +1460,7 @@ unsafe impl<F> TrustedIterator for Geomspace<F> {}
 unsafe impl<F> TrustedIterator for Logspace<F> {}
 unsafe impl<'a, A, D> TrustedIterator for Iter<'a, A, D> {}
 unsafe impl<'a, A, D> TrustedIterator for IterMut<'a, A, D> {}
+unsafe impl<'a, A, D> TrustedIterator for IndexedIter<'a, A, D> where D: Dimension {}
 unsafe impl<I> TrustedIterator for std::iter::Cloned<I> where I: TrustedIterator {}
 unsafe impl<I, F> TrustedIterator for std::iter::Map<I, F> where I: TrustedIterator {}
 unsafe impl<'a, A> TrustedIterator for slice::Iter<'a, A> {}
@@ -3,6 +3,7 @@ use crate::imp_prelude::*;
 use crate::IntoDimension;
 use crate::Layout;
 use crate::NdProducer;
+use crate::Slice;
 
 /// Window producer and iterable
 ///
@@ -20,6 +21,17 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
         E: IntoDimension<Dim = D>,
     {
         let window = window_size.into_dimension();
+        let mut unit_stride = D::zeros(window.ndim());
+        unit_stride.slice_mut().iter_mut().for_each(|s| *s = 1);
+        Windows::new_with_stride(a, window, unit_stride)
+    }
+
+    pub(crate) fn new_with_stride<E>(a: ArrayView<'a, A, D>, window_size: E, axis_strides: E) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let window = window_size.into_dimension();
+        let strides = axis_strides.into_dimension();
         ndassert!(
             a.ndim() == window.ndim(),
             concat!(
@@ -30,18 +42,33 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
             a.ndim(),
             a.shape()
         );
-        let mut size = a.dim;
-        for (sz, &ws) in size.slice_mut().iter_mut().zip(window.slice()) {
+        ndassert!(
+            a.ndim() == strides.ndim(),
+            concat!(
+                "Stride dimension {} does not match array dimension {} ",
+                "(with array of shape {:?})"
+            ),
+            strides.ndim(),
+            a.ndim(),
+            a.shape()
+        );
+        let mut size = a.dim.clone();
+        for ((sz, &ws), &stride) in size.slice_mut().iter_mut().zip(window.slice()).zip(strides.slice()) {
             assert_ne!(ws, 0, "window-size must not be zero!");
+            assert_ne!(stride, 0, "stride cannot have a dimension as zero!");
             // cannot use std::cmp::max(0, ..) since arithmetic underflow panics
-            *sz = if *sz < ws { 0 } else { *sz - ws + 1 };
+            *sz = if *sz < ws { 0 } else { (*sz - ws) / stride + 1 };
         }
-
         let window_strides = a.strides.clone();
 
+        let mut base = a;
+        for (ax, &stride) in strides.slice().iter().enumerate() {
+            base.slice_axis_inplace(Axis(ax), Slice::new(0, None, stride as isize));
+        }
+
         unsafe {
             Windows {
-                base: ArrayView::new(a.ptr, size, a.strides),
+                base: ArrayView::new(base.ptr, size, base.strides),
                 window,
                 strides: window_strides,
             }
@@ -136,6 +136,7 @@ pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
 pub use crate::error::{ErrorKind, ShapeError};
 pub use crate::indexes::{indices, indices_of};
+pub use crate::numeric::{interp, interp_with, ConvMode, NormKind, QuantileMethod, TieMethod};
 pub use crate::order::Order;
 pub use crate::slice::{
     MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim,
@@ -144,15 +145,18 @@ pub use crate::slice::{
 use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut};
 
-pub use crate::arraytraits::AsArray;
+pub use crate::arrayformat::{ColorMap, DebugValues, DisplayWith, FormatOptions, FormatWithIndex};
+pub use crate::arraytraits::{AsArray, CollectArray};
 #[cfg(feature = "std")]
 pub use crate::linalg_traits::NdFloat;
 pub use crate::linalg_traits::LinalgScalar;
 
 #[allow(deprecated)] // stack_new_axis
-pub use crate::stacking::{concatenate, stack, stack_new_axis};
+pub use crate::stacking::{concatenate, stack, stack_new_axis, stack_owned};
 
 pub use crate::math_cell::MathCell;
+#[cfg(feature = "std")]
+pub use crate::npy::{NpyElement, NpyError};
 pub use crate::impl_views::IndexLonger;
 pub use crate::shape_builder::{Shape, ShapeBuilder, ShapeArg, StrideShape};
 
@@ -193,6 +197,8 @@ mod linalg_traits;
 mod linspace;
 mod logspace;
 mod math_cell;
+#[cfg(feature = "std")]
+mod npy;
 mod numeric_util;
 mod order;
 mod partial;
@@ -0,0 +1,204 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::indices;
+use crate::{aview0, LinalgScalar};
+
+/// Contract two arrays as described by an Einstein summation `spec`, a
+/// restricted form of NumPy's `einsum`.
+///
+/// `spec` has the form `"ij,jk->ik"`: the axes of `a`, of `b` and of the
+/// result are labeled with lowercase ASCII letters. The element of the result
+/// at an index is the sum, over all the labels that are not in the result, of
+/// the products of the elements of `a` and `b` at the index given by their
+/// labels. For example, `"ij,jk->ik"` is the matrix product (and is computed
+/// with [`.dot()`](crate::ArrayBase::dot)), `"ij,ij->"` is the sum of the
+/// elementwise products, `"i,j->ij"` is the outer product and
+/// `"bij,bjk->bik"` is a batch of matrix products. Other cases are computed
+/// with a straightforward loop over all the labels.
+///
+/// The result always has dynamic dimension. For a single array, as with
+/// `"ij->ji"`, use [`contract1`].
+///
+/// ***Errors*** with `Unsupported` if `spec` is not two comma separated inputs
+/// and an output, if a label is not a lowercase ASCII letter, if a label is
+/// repeated within an input or within the output, or if an output label is in
+/// neither input.
+/// ***Errors*** with `IncompatibleShape` if the number of labels of an input
+/// differs from the number of axes of its array, or if the axes with the same
+/// label don't have the same length.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::contract;
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let b = array![[5., 6.], [7., 8.]];
+/// assert_eq!(contract(&a, &b, "ij,jk->ik").unwrap(), a.dot(&b).into_dyn());
+/// assert_eq!(contract(&a, &b, "ij,ij->").unwrap()[[]], 70.);
+/// assert_eq!(contract(&a, &b, "ij,jk->ki").unwrap(), a.dot(&b).t().into_dyn());
+/// assert!(contract(&a, &b, "ii,jk->k").is_err());
+/// ```
+pub fn contract<A, S1, S2, D1, D2>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    spec: &str,
+) -> Result<ArrayD<A>, ShapeError>
+where
+    A: LinalgScalar,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+{
+    let (a_labels, b_labels, out_labels) = parse_spec(spec)?;
+    if a_labels.len() != a.ndim() || b_labels.len() != b.ndim() {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+
+    // all the labels: those of the output first, then the summed ones
+    let mut labels = out_labels.clone();
+    for &label in a_labels.iter().chain(&b_labels) {
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    let mut lengths = Vec::with_capacity(labels.len());
+    for &label in &labels {
+        let a_len = a_labels.iter().position(|&l| l == label).map(|i| a.len_of(Axis(i)));
+        let b_len = b_labels.iter().position(|&l| l == label).map(|i| b.len_of(Axis(i)));
+        match (a_len, b_len) {
+            (Some(m), Some(n)) if m != n => return Err(from_kind(ErrorKind::IncompatibleShape)),
+            (Some(len), _) | (_, Some(len)) => lengths.push(len),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    let a = a.view().into_dyn();
+    let b = b.view().into_dyn();
+    // matrix product
+    if let ([i, j], [j2, k], [i2, k2]) = (&a_labels[..], &b_labels[..], &out_labels[..]) {
+        if j == j2 && i == i2 && k == k2 && i != j && j != k && i != k {
+            let a = a.into_dimensionality::<Ix2>().unwrap();
+            let b = b.into_dimensionality::<Ix2>().unwrap();
+            return Ok(a.dot(&b).into_dyn());
+        }
+    }
+
+    // the position in `labels` of each axis of the inputs
+    let label_pos = |l: &[u8]| -> Vec<usize> {
+        l.iter().map(|label| labels.iter().position(|x| x == label).unwrap()).collect()
+    };
+    let (a_pos, b_pos) = (label_pos(&a_labels), label_pos(&b_labels));
+    let mut result = ArrayD::zeros(&lengths[..out_labels.len()]);
+    let mut a_index = vec![0; a_pos.len()];
+    let mut b_index = vec![0; b_pos.len()];
+    for index in indices(&lengths[..]) {
+        let index = index.slice();
+        for (ix, &pos) in a_index.iter_mut().zip(&a_pos) {
+            *ix = index[pos];
+        }
+        for (ix, &pos) in b_index.iter_mut().zip(&b_pos) {
+            *ix = index[pos];
+        }
+        let elt = &mut result[&index[..out_labels.len()]];
+        *elt = *elt + a[&a_index[..]] * b[&b_index[..]];
+    }
+    Ok(result)
+}
+
+/// Contract a single array as described by an Einstein summation `spec`, a
+/// restricted form of NumPy's `einsum`.
+///
+/// `spec` has the form `"ij->ji"`, with the same rules as for [`contract`].
+/// If the output has all the labels of the input, the result is the array
+/// with its axes permuted as with
+/// [`.permuted_axes()`](crate::ArrayBase::permuted_axes); for example
+/// `"ij->ji"` is the transpose. Otherwise the axes that are not in the output
+/// are summed over; for example `"ij->i"` is the sum of each row and `"ij->"`
+/// is the sum of all elements.
+///
+/// ***Errors*** with `Unsupported` if `spec` is not one input and an output,
+/// if a label is not a lowercase ASCII letter, if a label is repeated within
+/// the input or within the output, or if an output label is not in the input.
+/// ***Errors*** with `IncompatibleShape` if the number of labels of the input
+/// differs from the number of axes of `a`.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::contract1;
+///
+/// let a = array![[1., 2., 3.], [4., 5., 6.]];
+/// assert_eq!(contract1(&a, "ij->ji").unwrap(), a.t().into_dyn());
+/// assert_eq!(contract1(&a, "ij->i").unwrap(), array![6., 15.].into_dyn());
+/// assert!(contract1(&a, "i->i").is_err());
+/// ```
+pub fn contract1<A, S, D>(a: &ArrayBase<S, D>, spec: &str) -> Result<ArrayD<A>, ShapeError>
+where
+    A: LinalgScalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let (input, output) = match spec.find("->") {
+        Some(i) if !spec[..i].contains(',') => (&spec[..i], &spec[i + 2..]),
+        _ => return Err(from_kind(ErrorKind::Unsupported)),
+    };
+    // contract with a one as the second operand
+    let spec = format!("{},->{}", input, output);
+    let (labels, _, out_labels) = parse_spec(&spec)?;
+    if labels.len() != a.ndim() {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    if out_labels.len() == labels.len() {
+        let axes: Vec<usize> = out_labels
+            .iter()
+            .map(|label| labels.iter().position(|l| l == label).unwrap())
+            .collect();
+        return Ok(a.view().into_dyn().permuted_axes(&axes[..]).to_owned());
+    }
+    contract(a, &aview0(&A::one()), &spec)
+}
+
+/// The labels of the two inputs and of the output of a spec.
+type Labels = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+/// Parse a spec of the form `"ij,jk->ik"` into the labels of the two inputs
+/// and of the output.
+fn parse_spec(spec: &str) -> Result<Labels, ShapeError> {
+    let unsupported = || from_kind(ErrorKind::Unsupported);
+    let mut parts = spec.split("->");
+    let (inputs, output) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(inputs), Some(output), None) => (inputs, output),
+        _ => return Err(unsupported()),
+    };
+    let mut inputs = inputs.split(',');
+    let (a, b) = match (inputs.next(), inputs.next(), inputs.next()) {
+        (Some(a), Some(b), None) => (a, b),
+        _ => return Err(unsupported()),
+    };
+    let labels = |s: &str| -> Result<Vec<u8>, ShapeError> {
+        let labels = s.as_bytes().to_vec();
+        for (i, label) in labels.iter().enumerate() {
+            if !label.is_ascii_lowercase() || labels[..i].contains(label) {
+                return Err(unsupported());
+            }
+        }
+        Ok(labels)
+    };
+    let (a, b, output) = (labels(a)?, labels(b)?, labels(output)?);
+    if output.iter().any(|label| !a.contains(label) && !b.contains(label)) {
+        return Err(unsupported());
+    }
+    Ok((a, b, output))
+}
@@ -15,7 +15,9 @@ use crate::numeric_util;
 use crate::{LinalgScalar, Zip};
 
 use std::any::TypeId;
+use std::ops::{Mul, Sub};
 use std::mem::MaybeUninit;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use num_complex::Complex;
@@ -33,6 +35,9 @@ use cblas_sys as blas_sys;
 #[cfg(feature = "blas")]
 use cblas_sys::{CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_LAYOUT};
 
+/// block size of the loop tiling in `.dot()` for element types without a gemm
+const DOT_BLOCK_SIZE: usize = 64;
+
 /// len of vector before we use blas
 #[cfg(feature = "blas")]
 const DOT_BLAS_CUTOFF: usize = 32;
@@ -71,6 +76,36 @@ where
         Dot::dot(self, rhs)
     }
 
+    /// Return the cross product of the three-dimensional vectors `self` and
+    /// `rhs`.
+    ///
+    /// **Panics** if `self` or `rhs` does not have length 3.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let x = array![1, 0, 0];
+    /// let y = array![0, 1, 0];
+    /// assert_eq!(x.cross(&y), array![0, 0, 1]);
+    /// assert_eq!(y.cross(&x), array![0, 0, -1]);
+    /// ```
+    pub fn cross<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> Array1<A>
+    where
+        S2: Data<Elem = A>,
+        A: Clone + Mul<Output = A> + Sub<Output = A>,
+    {
+        assert!(
+            self.len() == 3 && rhs.len() == 3,
+            "ndarray: cross product requires vectors of length 3, got lengths {} and {}",
+            self.len(),
+            rhs.len()
+        );
+        let term = |i: usize, j: usize| {
+            self[i].clone() * rhs[j].clone() - self[j].clone() * rhs[i].clone()
+        };
+        array![term(1, 2), term(2, 0), term(0, 1)]
+    }
+
     fn dot_generic<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
     where
         S2: Data<Elem = A>,
@@ -235,7 +270,9 @@ where
     ///
     /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`
     /// when memory layout allows. The default matrixmultiply backend
-    /// is otherwise used for `f32, f64` for all memory layouts.
+    /// is otherwise used for `f32, f64` for all memory layouts. Matrix
+    /// products of other element types use the loop tiling of
+    /// [`.dot_blocked()`](Self::dot_blocked) with a block size of 64.
     ///
     /// ```
     /// use ndarray::arr2;
@@ -256,6 +293,86 @@ where
     {
         Dot::dot(self, rhs)
     }
+
+    /// Perform matrix multiplication of `self` and `rhs` with a simple loop
+    /// tiling, processing `block` × `block` × `block` tiles at a time.
+    ///
+    /// The array shapes must agree in the way that if `self` is *M* × *N*,
+    /// then `rhs` is *N* × *K*; the result has shape *M* × *K*.
+    ///
+    /// The `block` size only changes the traversal order, and can be tuned for
+    /// the cache sizes of the machine. Each result element is accumulated in
+    /// increasing order along the shared axis, so the result does not depend
+    /// on `block`; it is exactly equal to `.dot()` for integer elements.
+    ///
+    /// *Note:* For `f32`, `f64` and complex elements, `.dot()` uses the
+    /// matrixmultiply (or blas) backend, which does its own, much more refined,
+    /// blocking and is usually faster. For other element types, `.dot()` is
+    /// this method with a block size of 64.
+    ///
+    /// **Panics** if shapes are incompatible, if `block` is zero, or if the
+    /// number of elements in the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_shape_fn((50, 30), |(i, j)| (i * j) as i64 % 7);
+    /// let b = Array::from_shape_fn((30, 40), |(i, j)| (i + j) as i64 % 5);
+    ///
+    /// assert_eq!(a.dot_blocked(&b, 16), a.dot(&b));
+    /// assert_eq!(a.dot_blocked(&b, 7), a.dot(&b));
+    /// ```
+    pub fn dot_blocked<S2>(&self, rhs: &ArrayBase<S2, Ix2>, block: usize) -> Array2<A>
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        assert!(block > 0, "ndarray: block size must be positive");
+        let ((m, k), (k2, n)) = (self.dim(), rhs.dim());
+        if k != k2 || m.checked_mul(n).is_none() {
+            dot_shape_error(m, k, k2, n);
+        }
+        let mut c = Array2::zeros((m, n));
+        mat_mul_blocked(&self.view(), &rhs.view(), &mut c.view_mut(), block);
+        c
+    }
+}
+
+/// Compute `c += lhs * rhs` with `block` × `block` × `block` tiles.
+///
+/// The shapes must already be checked to agree.
+fn mat_mul_blocked<A>(
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    c: &mut ArrayViewMut2<'_, A>,
+    block: usize,
+) where
+    A: LinalgScalar,
+{
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    for i0 in (0..m).step_by(block) {
+        let i1 = Ord::min(i0 + block, m);
+        // blocks along the shared axis are visited in increasing order, so that each
+        // element is accumulated in the same order as without blocking
+        for x0 in (0..k).step_by(block) {
+            let x1 = Ord::min(x0 + block, k);
+            for j0 in (0..n).step_by(block) {
+                let j1 = Ord::min(j0 + block, n);
+                for i in i0..i1 {
+                    for x in x0..x1 {
+                        // Safe because the indices are within the checked shapes
+                        unsafe {
+                            let a = *lhs.uget((i, x));
+                            for j in j0..j1 {
+                                let elt = c.uget_mut((i, j));
+                                *elt = *elt + a * *rhs.uget((x, j));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<A, S, S2> Dot<ArrayBase<S2, Ix2>> for ArrayBase<S, Ix2>
@@ -283,11 +400,83 @@ where
             v.set_len(m * n);
             c = Array::from_shape_vec_unchecked((m, n).set_f(column_major), v);
         }
-        mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c.view_mut());
+        if !(same_type::<A, f32>()
+            || same_type::<A, f64>()
+            || same_type::<A, c32>()
+            || same_type::<A, c64>())
+        {
+            c.fill(A::zero());
+            mat_mul_blocked(&a, &b, &mut c.view_mut(), DOT_BLOCK_SIZE);
+        } else if !mat_mul_small_square(&a, &b, &mut c.view_mut()) {
+            mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c.view_mut());
+        }
         c
     }
 }
 
+/// Compute `c = lhs * rhs` with an unrolled kernel if the operands are `f32` or
+/// `f64` square matrices of size 2, 3 or 4, where the call overhead of the
+/// general matrix multiplication dominates.
+///
+/// Return `false` (and leave `c` untouched) if no kernel applies.
+fn mat_mul_small_square<A>(
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    c: &mut ArrayViewMut2<'_, A>,
+) -> bool
+where
+    A: LinalgScalar,
+{
+    if !(same_type::<A, f32>() || same_type::<A, f64>()) {
+        return false;
+    }
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    if m != k || k != n {
+        return false;
+    }
+    match n {
+        2 => mat_mul_square_kernel::<A, 2>(lhs, rhs, c),
+        3 => mat_mul_square_kernel::<A, 3>(lhs, rhs, c),
+        4 => mat_mul_square_kernel::<A, 4>(lhs, rhs, c),
+        _ => return false,
+    }
+    true
+}
+
+/// Unrolled `N × N` matrix multiplication; the operands are copied into
+/// fixed size arrays so that all loops have constant bounds.
+#[inline(always)]
+fn mat_mul_square_kernel<A, const N: usize>(
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    c: &mut ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    debug_assert!(lhs.dim() == (N, N) && rhs.dim() == (N, N) && c.dim() == (N, N));
+    let mut a = [[A::zero(); N]; N];
+    let mut b = [[A::zero(); N]; N];
+    for (i, (a_row, b_row)) in a.iter_mut().zip(&mut b).enumerate() {
+        for (j, (a_elt, b_elt)) in a_row.iter_mut().zip(b_row).enumerate() {
+            unsafe {
+                *a_elt = *lhs.uget((i, j));
+                *b_elt = *rhs.uget((i, j));
+            }
+        }
+    }
+    for (i, a_row) in a.iter().enumerate() {
+        for j in 0..N {
+            let mut sum = a_row[0] * b[0][j];
+            for (&a_elt, b_row) in a_row.iter().zip(&b).skip(1) {
+                sum = sum + a_elt * b_row[j];
+            }
+            unsafe {
+                *c.uget_mut((i, j)) = sum;
+            }
+        }
+    }
+}
+
 /// Assumes that `m` and `n` are ≤ `isize::MAX`.
 #[cold]
 #[inline(never)]
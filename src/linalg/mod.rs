@@ -12,5 +12,7 @@ pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::Dot;
 pub use self::impl_linalg::kron;
+pub use self::contract::{contract, contract1};
 
+mod contract;
 mod impl_linalg;
@@ -16,6 +16,22 @@ pub struct Linspace<F> {
     step: F,
     index: usize,
     len: usize,
+    /// Index and exact value of the endpoint, if it is part of the sequence
+    end: Option<(usize, F)>,
+}
+
+impl<F> Linspace<F>
+where
+    F: Float,
+{
+    #[inline]
+    fn value(&self, i: usize) -> F {
+        match self.end {
+            Some((last, end)) if i == last => end,
+            // Calculate the value just like numpy.linspace does
+            _ => self.start + self.step * F::from(i).unwrap(),
+        }
+    }
 }
 
 impl<F> Iterator for Linspace<F>
@@ -29,10 +45,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             let i = self.index;
             self.index += 1;
-            Some(self.start + self.step * F::from(i).unwrap())
+            Some(self.value(i))
         }
     }
 
@@ -52,10 +67,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             self.len -= 1;
             let i = self.len;
-            Some(self.start + self.step * F::from(i).unwrap())
+            Some(self.value(i))
         }
     }
 }
@@ -64,7 +78,9 @@ impl<F> ExactSizeIterator for Linspace<F> where Linspace<F>: Iterator {}
 
 /// Return an iterator of evenly spaced floats.
 ///
-/// The `Linspace` has `n` elements from `a` to `b` (inclusive).
+/// The `Linspace` has `n` elements from `a` to `b` (inclusive). If `n > 1`,
+/// the first element is exactly `a` and the last element is exactly `b`; if
+/// `n == 1`, the only element is `a`.
 ///
 /// The iterator element type is `F`, where `F` must implement `Float`, e.g.
 /// `f32` or `f64`.
@@ -75,17 +91,18 @@ pub fn linspace<F>(a: F, b: F, n: usize) -> Linspace<F>
 where
     F: Float,
 {
-    let step = if n > 1 {
+    let (step, end) = if n > 1 {
         let num_steps = F::from(n - 1).expect("Converting number of steps to `A` must not fail.");
-        (b - a) / num_steps
+        ((b - a) / num_steps, Some((n - 1, b)))
     } else {
-        F::zero()
+        (F::zero(), None)
     };
     Linspace {
         start: a,
         step,
         index: 0,
         len: n,
+        end,
     }
 }
 
@@ -114,5 +131,38 @@ where
              different from the sign of `step`.",
         ),
         index: 0,
+        end: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{linspace, range};
+
+    #[test]
+    fn linspace_endpoints() {
+        let v: Vec<f64> = linspace(0.1, 0.7, 7).collect();
+        assert_eq!(v.len(), 7);
+        assert_eq!(v[0], 0.1);
+        assert_eq!(v[6], 0.7);
+
+        let v: Vec<f64> = linspace(0.1, 0.7, 7).rev().collect();
+        assert_eq!(v[0], 0.7);
+        assert_eq!(v[6], 0.1);
+    }
+
+    #[test]
+    fn linspace_short() {
+        assert_eq!(linspace(1., 2., 0).count(), 0);
+        assert_eq!(linspace(1., 2., 1).collect::<Vec<f64>>(), vec![1.]);
+        assert_eq!(linspace(1., 2., 2).collect::<Vec<f64>>(), vec![1., 2.]);
+    }
+
+    #[test]
+    fn range_len() {
+        assert_eq!(range(0., 5., 1.).len(), 5);
+        assert_eq!(range(0., 5.5, 1.).len(), 6);
+        assert_eq!(range(5., 0., -2.).collect::<Vec<f64>>(), vec![5., 3., 1.]);
+        assert_eq!(range(0., 0., 1.).len(), 0);
     }
 }
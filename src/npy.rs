@@ -0,0 +1,357 @@
+// Copyright 2021 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reading and writing arrays in the NumPy `.npy` format.
+//!
+//! See the [format description][npy] for details.
+//!
+//! [npy]: https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::mem::size_of;
+
+use crate::dimension;
+use crate::error::{ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::{IxDyn, ShapeBuilder};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The header is padded so that the data starts at a multiple of this.
+const HEADER_ALIGN: usize = 64;
+
+/// The data is written to the writer in pieces of about this many bytes.
+const WRITE_BUFFER_SIZE: usize = 8192;
+
+/// An element type that can be read from and written to `.npy` files.
+///
+/// This trait is implemented for `f32`, `f64`, `i32` and `i64`, and is private to implement.
+pub trait NpyElement: Copy {
+    /// The type code of the element, without the byte order character, for example `"f8"`.
+    #[doc(hidden)]
+    const TYPE_CODE: &'static str;
+
+    #[doc(hidden)]
+    fn write_le(self, out: &mut Vec<u8>);
+
+    #[doc(hidden)]
+    fn read_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    private_decl! {}
+}
+
+macro_rules! impl_npy_element {
+    ($($elem:ty => $code:expr),*) => {
+        $(
+        impl NpyElement for $elem {
+            const TYPE_CODE: &'static str = $code;
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let mut buf = [0; size_of::<$elem>()];
+                buf.copy_from_slice(bytes);
+                if big_endian {
+                    <$elem>::from_be_bytes(buf)
+                } else {
+                    <$elem>::from_le_bytes(buf)
+                }
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+impl_npy_element!(f32 => "f4", f64 => "f8", i32 => "i4", i64 => "i8");
+
+/// An error reading or writing a `.npy` file.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum NpyError {
+    /// An I/O error from the underlying reader or writer.
+    Io(io::Error),
+    /// The data does not start with the `.npy` magic string.
+    MagicString,
+    /// The file uses a format version that is not supported (the major and minor version).
+    Version(u8, u8),
+    /// The header could not be parsed.
+    Header(String),
+    /// The element type in the file (first) does not match the requested element type (second).
+    DtypeMismatch(String, &'static str),
+    /// The shape in the file does not fit the requested dimensionality, or is too large.
+    Shape(ShapeError),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Io(err) => write!(f, "npy: I/O error: {}", err),
+            NpyError::MagicString => write!(f, "npy: missing magic string, not an npy file"),
+            NpyError::Version(major, minor) =>
+                write!(f, "npy: unsupported format version {}.{}", major, minor),
+            NpyError::Header(msg) => write!(f, "npy: malformed header: {}", msg),
+            NpyError::DtypeMismatch(found, expected) =>
+                write!(f, "npy: file has dtype {:?}, expected element type {:?}", found, expected),
+            NpyError::Shape(err) => write!(f, "npy: {}", err),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(err) => Some(err),
+            NpyError::Shape(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(err: io::Error) -> Self {
+        NpyError::Io(err)
+    }
+}
+
+impl From<ShapeError> for NpyError {
+    fn from(err: ShapeError) -> Self {
+        NpyError::Shape(err)
+    }
+}
+
+/// # Reading and writing `.npy` files
+///
+/// **Requires crate feature `"std"`**
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: NpyElement,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Write the array to `writer` in the NumPy `.npy` format.
+    ///
+    /// The data is written in little endian byte order. Arrays in Fortran (column major)
+    /// layout are written with `fortran_order: True`, all other arrays are written in logical
+    /// (row major) order.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let mut file = Vec::new();
+    /// a.write_npy(&mut file).unwrap();
+    ///
+    /// let b = Array2::<f64>::read_npy(&file[..]).unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn write_npy<W: io::Write>(&self, mut writer: W) -> Result<(), NpyError> {
+        let fortran_order = self.ndim() > 1 && !self.is_standard_layout() && self.t().is_standard_layout();
+        let shape = match self.shape() {
+            [n] => format!("({},)", n),
+            shape => {
+                let axes: Vec<_> = shape.iter().map(|n| format!("{}", n)).collect();
+                format!("({})", axes.join(", "))
+            }
+        };
+        let mut header = format!(
+            "{{'descr': '<{}', 'fortran_order': {}, 'shape': {}, }}",
+            A::TYPE_CODE,
+            if fortran_order { "True" } else { "False" },
+            shape);
+
+        // magic + version + header length, then the header padded with spaces and ended with a
+        // newline
+        let mut preamble_len = MAGIC.len() + 2 + 2;
+        let mut header_len = round_up(preamble_len + header.len() + 1, HEADER_ALIGN) - preamble_len;
+        let version = if header_len <= u16::MAX as usize {
+            1
+        } else {
+            preamble_len += 2;
+            header_len = round_up(preamble_len + header.len() + 1, HEADER_ALIGN) - preamble_len;
+            2
+        };
+        while header.len() < header_len - 1 {
+            header.push(' ');
+        }
+        header.push('\n');
+
+        let mut out = Vec::with_capacity(WRITE_BUFFER_SIZE.max(preamble_len + header_len));
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[version, 0]);
+        if version == 1 {
+            out.extend_from_slice(&(header_len as u16).to_le_bytes());
+        } else {
+            out.extend_from_slice(&(header_len as u32).to_le_bytes());
+        }
+        out.extend_from_slice(header.as_bytes());
+        writer.write_all(&out)?;
+        out.clear();
+
+        // stream the data through a buffer of bounded size
+        let view = if fortran_order {
+            // the transpose is in standard layout, so this is memory order
+            self.t()
+        } else {
+            self.view()
+        };
+        for &x in view.iter() {
+            x.write_le(&mut out);
+            if out.len() >= WRITE_BUFFER_SIZE {
+                writer.write_all(&out)?;
+                out.clear();
+            }
+        }
+        writer.write_all(&out)?;
+        Ok(())
+    }
+}
+
+/// # Reading and writing `.npy` files
+///
+/// **Requires crate feature `"std"`**
+impl<A, D> Array<A, D>
+where
+    A: NpyElement,
+    D: Dimension,
+{
+    /// Read an array from `reader` in the NumPy `.npy` format.
+    ///
+    /// Both C (row major) and Fortran (column major) ordered data is supported, in either byte
+    /// order; the resulting array has the memory layout of the file.
+    ///
+    /// ***Errors*** if the data is not a valid `.npy` file, if the dtype in the file does not
+    /// match `A`, or if the number of axes in the file does not match `D`.
+    pub fn read_npy<R: io::Read>(mut reader: R) -> Result<Self, NpyError> {
+        let mut magic = [0; 6];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(NpyError::MagicString);
+        }
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let header_len = match version {
+            [1, 0] => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            [2, 0] | [3, 0] => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            [major, minor] => return Err(NpyError::Version(major, minor)),
+        };
+        let header = read_bytes(&mut reader, header_len)?;
+        let header = String::from_utf8(header)
+            .map_err(|_| NpyError::Header("header is not valid text".into()))?;
+
+        let descr = header_str(&header, "descr")?;
+        let (byte_order, type_code) = descr.split_at(descr.len().min(1));
+        let big_endian = match byte_order {
+            "<" | "|" => false,
+            ">" => true,
+            "=" => cfg!(target_endian = "big"),
+            _ => return Err(NpyError::DtypeMismatch(descr.into(), A::TYPE_CODE)),
+        };
+        if type_code != A::TYPE_CODE {
+            return Err(NpyError::DtypeMismatch(descr.into(), A::TYPE_CODE));
+        }
+        let fortran_order = match header_value(&header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            value => return Err(NpyError::Header(format!("invalid fortran_order {:?}", value))),
+        };
+        let shape = header_shape(&header)?;
+        let dim = D::from_dimension(&IxDyn(&shape))
+            .ok_or_else(|| ShapeError::from_kind(ErrorKind::IncompatibleShape))?;
+        let len = dimension::size_of_shape_checked(&dim)?;
+        let nbytes = len
+            .checked_mul(size_of::<A>())
+            .ok_or_else(|| ShapeError::from_kind(ErrorKind::Overflow))?;
+
+        let data = read_bytes(&mut reader, nbytes)?;
+        let v = data
+            .chunks_exact(size_of::<A>())
+            .map(|bytes| A::read_bytes(bytes, big_endian))
+            .collect();
+        Ok(Array::from_shape_vec(dim.set_f(fortran_order), v)?)
+    }
+}
+
+/// Read exactly `nbytes` bytes from `reader`.
+///
+/// The buffer grows with the data actually read, so that a bogus length in the header does not
+/// allocate memory up front.
+fn read_bytes<R: io::Read>(reader: &mut R, nbytes: usize) -> Result<Vec<u8>, NpyError> {
+    let mut data = Vec::new();
+    reader.take(nbytes as u64).read_to_end(&mut data)?;
+    if data.len() != nbytes {
+        let msg = "npy: the data is shorter than declared in the header";
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, msg).into());
+    }
+    Ok(data)
+}
+
+fn round_up(n: usize, multiple: usize) -> usize {
+    n + (multiple - n % multiple) % multiple
+}
+
+/// Return the unparsed value for `key` in the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let start = header
+        .find(&format!("'{}'", key))
+        .or_else(|| header.find(&format!("\"{}\"", key)))
+        .ok_or_else(|| NpyError::Header(format!("missing key {:?}", key)))?;
+    let rest = header[start + key.len() + 2..].trim_start();
+    let rest = rest
+        .strip_prefix(':')
+        .ok_or_else(|| NpyError::Header(format!("missing value for {:?}", key)))?
+        .trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|i| i + 1)
+    } else {
+        rest.find(&[',', '}'][..])
+    };
+    let end = end.ok_or_else(|| NpyError::Header(format!("unterminated value for {:?}", key)))?;
+    Ok(rest[..end].trim_end())
+}
+
+/// Return the value for `key` in the header dictionary, which must be a string.
+fn header_str<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let value = header_value(header, key)?;
+    let unquoted = value
+        .strip_prefix('\'').and_then(|v| v.strip_suffix('\''))
+        .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')));
+    unquoted.ok_or_else(|| NpyError::Header(format!("expected a string for {:?}", key)))
+}
+
+fn header_shape(header: &str) -> Result<Vec<Ix>, NpyError> {
+    let value = header_value(header, "shape")?;
+    let axes = value
+        .strip_prefix('(').and_then(|v| v.strip_suffix(')'))
+        .ok_or_else(|| NpyError::Header(format!("invalid shape {:?}", value)))?;
+    axes.split(',')
+        .map(str::trim)
+        .filter(|axis| !axis.is_empty())
+        .map(|axis| {
+            axis.trim_end_matches('L').parse()
+                .map_err(|_| NpyError::Header(format!("invalid shape {:?}", value)))
+        })
+        .collect()
+}
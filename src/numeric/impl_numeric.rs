@@ -9,10 +9,18 @@
 #[cfg(feature = "std")]
 use num_traits::Float;
 use num_traits::{self, FromPrimitive, Zero};
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
 use std::ops::{Add, Div, Mul};
 
 use crate::imp_prelude::*;
 use crate::numeric_util;
+#[cfg(feature = "std")]
+use crate::{NormKind, QuantileMethod};
+use crate::{ConvMode, TieMethod, Zip};
 
 /// # Numerical Methods for Arrays
 impl<A, S, D> ArrayBase<S, D>
@@ -85,6 +93,43 @@ where
         }
     }
 
+    /// Return the arithmetic mean of the elements where `mask` is `true`.
+    ///
+    /// Elements where `mask` is `false` are ignored, for example because they
+    /// are missing values. If no element is selected, the result is NaN.
+    ///
+    /// **Panics** if the shapes of the array and `mask` are not equal.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., -99., 3.], [4., 5., -99.0f64]];
+    /// let valid = a.mapv(|x| x != -99.);
+    /// assert_eq!(a.masked_mean(&valid), 3.25);
+    /// assert!(a.masked_mean(&valid.mapv(|_| false)).is_nan());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn masked_mean<S2>(&self, mask: &ArrayBase<S2, D>) -> A
+    where
+        A: Float,
+        S2: Data<Elem = bool>,
+    {
+        let (sum, count) = Zip::from(self)
+            .and(mask)
+            .fold((A::zero(), 0usize), |(sum, count), &x, &selected| {
+                if selected {
+                    (sum + x, count + 1)
+                } else {
+                    (sum, count)
+                }
+            });
+        if count == 0 {
+            A::nan()
+        } else {
+            sum / A::from(count).expect("Converting number of elements to `A` must not fail.")
+        }
+    }
+
     /// Return the product of all elements in the array.
     ///
     /// ```
@@ -112,6 +157,130 @@ where
         sum
     }
 
+    /// Return a reference to the least element of the array.
+    ///
+    /// If several elements are equally least, it is unspecified which of them
+    /// is returned.
+    ///
+    /// Returns `None` if the array is empty, or if any two elements are not
+    /// comparable, for example if a floating point array contains NaN; see
+    /// [`.min_skipnan()`](Self::min_skipnan) to ignore NaN instead.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3., 1.], [-2., 5.]];
+    /// assert_eq!(a.min_element(), Some(&-2.));
+    /// assert_eq!(array![1., f64::NAN].min_element(), None);
+    /// assert_eq!(array![[3, 1], [-2, 5]].min_element(), Some(&-2));
+    /// ```
+    pub fn min_element(&self) -> Option<&A>
+    where
+        A: PartialOrd,
+    {
+        self.minmax_element().map(|(min, _)| min)
+    }
+
+    /// Return a reference to the greatest element of the array.
+    ///
+    /// If several elements are equally greatest, it is unspecified which of
+    /// them is returned.
+    ///
+    /// Returns `None` if the array is empty, or if any two elements are not
+    /// comparable, for example if a floating point array contains NaN; see
+    /// [`.max_skipnan()`](Self::max_skipnan) to ignore NaN instead.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3., 1.], [-2., 5.]];
+    /// assert_eq!(a.max_element(), Some(&5.));
+    /// ```
+    pub fn max_element(&self) -> Option<&A>
+    where
+        A: PartialOrd,
+    {
+        self.minmax_element().map(|(_, max)| max)
+    }
+
+    /// Return references to the least and the greatest element of the array,
+    /// in one traversal.
+    ///
+    /// Returns `None` if the array is empty, or if any two elements are not
+    /// comparable, for example if a floating point array contains NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 1], [-2, 5]];
+    /// assert_eq!(a.minmax_element(), Some((&-2, &5)));
+    /// ```
+    pub fn minmax_element(&self) -> Option<(&A, &A)>
+    where
+        A: PartialOrd,
+    {
+        let mut iter = self.iter();
+        let first = iter.next()?;
+        // an element that is not comparable with itself, like NaN, is not comparable with any
+        first.partial_cmp(first)?;
+        iter.try_fold((first, first), |(min, max), elt| {
+            if elt.partial_cmp(min)? == Ordering::Less {
+                Some((elt, max))
+            } else if elt.partial_cmp(max)? == Ordering::Greater {
+                Some((min, elt))
+            } else {
+                Some((min, max))
+            }
+        })
+    }
+
+    /// Return a reference to the least element of the array, ignoring NaN.
+    ///
+    /// Returns `None` if the array is empty or all its elements are NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![3., f64::NAN, -2.];
+    /// assert_eq!(a.min_skipnan(), Some(&-2.));
+    /// assert_eq!(array![f64::NAN].min_skipnan(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn min_skipnan(&self) -> Option<&A>
+    where
+        A: Float,
+    {
+        self.iter()
+            .filter(|elt| !elt.is_nan())
+            .fold(None, |min, elt| match min {
+                Some(min) if min <= elt => Some(min),
+                _ => Some(elt),
+            })
+    }
+
+    /// Return a reference to the greatest element of the array, ignoring NaN.
+    ///
+    /// Returns `None` if the array is empty or all its elements are NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![3., f64::NAN, -2.];
+    /// assert_eq!(a.max_skipnan(), Some(&3.));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn max_skipnan(&self) -> Option<&A>
+    where
+        A: Float,
+    {
+        self.iter()
+            .filter(|elt| !elt.is_nan())
+            .fold(None, |max, elt| match max {
+                Some(max) if max >= elt => Some(max),
+                _ => Some(elt),
+            })
+    }
+
     /// Return variance of elements in the array.
     ///
     /// The variance is computed using the [Welford one-pass
@@ -239,6 +408,9 @@ where
     /// );
     /// ```
     ///
+    /// For other reductions along an axis, see
+    /// [`.reduce_axis()`](ArrayBase::reduce_axis).
+    ///
     /// **Panics** if `axis` is out of bounds.
     pub fn sum_axis(&self, axis: Axis) -> Array<A, D::Smaller>
     where
@@ -411,4 +583,997 @@ where
     {
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
+
+    /// Return the `q`-quantile of each lane along `axis`.
+    ///
+    /// The quantile of a lane is the value at position `h = q * (n - 1)` of the
+    /// lane sorted in increasing order, where `n` is the length of the lane;
+    /// when `h` is not an integer, `method` decides how the value is computed
+    /// from the elements next to it. For example, with `q = 0.5` and
+    /// `QuantileMethod::Midpoint` or `QuantileMethod::Linear`, this is the
+    /// median. If a lane contains NaN, its quantile is NaN.
+    ///
+    /// Each lane is copied into a temporary buffer, in which the elements
+    /// needed are selected without fully sorting it.
+    ///
+    /// **Panics** if `q` is not in `0. ..= 1.`, if `axis` is out of bounds, or
+    /// if the length of `axis` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, QuantileMethod};
+    ///
+    /// let a = array![[4., 1., 3., 2.],
+    ///                [0., 9., 5., 7.]];
+    /// // h = 0.25 * 3 = 0.75, between the first and the second element
+    /// assert_eq!(a.quantile_axis(Axis(1), 0.25, QuantileMethod::Linear), array![1.75, 3.75]);
+    /// assert_eq!(a.quantile_axis(Axis(1), 0.25, QuantileMethod::Lower), array![1., 0.]);
+    /// assert_eq!(a.quantile_axis(Axis(1), 0.25, QuantileMethod::Nearest), array![2., 5.]);
+    /// assert_eq!(a.quantile_axis(Axis(0), 1., QuantileMethod::Linear), array![4., 9., 5., 7.]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn quantile_axis(&self, axis: Axis, q: f64, method: QuantileMethod) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        assert!(
+            (0. ..=1.).contains(&q),
+            "ndarray: quantile_axis: q must be in the range 0. ..= 1., got {}",
+            q
+        );
+        let n = self.len_of(axis);
+        assert!(n > 0, "ndarray: quantile_axis: the length of the axis must be nonzero");
+        let h = q * (n - 1) as f64;
+        let mut buf = Vec::with_capacity(n);
+        self.map_axis(axis, |lane| {
+            buf.clear();
+            buf.extend(lane.iter().cloned());
+            quantile_of_buffer(&mut buf, h, method)
+        })
+    }
+
+    /// Return the median of each lane along `axis`.
+    ///
+    /// For a lane of odd length this is its middle element in sorted order,
+    /// and for a lane of even length the mean of the two middle elements. If a
+    /// lane contains NaN, its median is NaN. This is the same as
+    /// [`.quantile_axis(axis, 0.5, QuantileMethod::Midpoint)`](ArrayBase::quantile_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3., 1., 2.],
+    ///                [6., 4., 9.]];
+    /// assert_eq!(a.median_axis(Axis(1)), array![2., 6.]);
+    /// assert_eq!(a.median_axis(Axis(0)), array![4.5, 2.5, 5.5]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn median_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.quantile_axis(axis, 0.5, QuantileMethod::Midpoint)
+    }
+
+    /// Return the most frequent element of each lane along `axis`.
+    ///
+    /// If several elements are equally frequent in a lane, the one that occurs
+    /// first in the lane (in order of increasing index along `axis`) is
+    /// returned. The elements of each lane are counted in a `HashMap`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let votes = array![[1, 2, 2, 0],
+    ///                    [3, 1, 1, 3]];
+    /// assert_eq!(votes.mode_axis(Axis(1)), array![2, 3]);
+    /// assert_eq!(votes.mode_axis(Axis(0)), array![1, 2, 2, 0]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn mode_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Hash + Eq + Clone,
+        D: RemoveAxis,
+    {
+        assert!(
+            self.len_of(axis) > 0,
+            "ndarray: mode_axis: the length of the axis must be nonzero"
+        );
+        let mut counts = HashMap::new();
+        self.map_axis(axis, |lane| {
+            counts.clear();
+            // count the elements, and remember where each was first seen
+            for (i, elt) in lane.into_iter().enumerate() {
+                counts.entry(elt).or_insert((0, i)).0 += 1;
+            }
+            let (mode, _) = counts
+                .iter()
+                .max_by(|(_, &(c1, i1)), (_, &(c2, i2))| c1.cmp(&c2).then(i2.cmp(&i1)))
+                .unwrap();
+            (*mode).clone()
+        })
+    }
+
+    /// Return the rank of each element within its lane along `axis`.
+    ///
+    /// The ranks start at 1 for the least element of each lane, and `tie`
+    /// decides the ranks of equal elements; the result has the same shape as
+    /// `self`. The order of each lane is found with
+    /// [`.argsort_axis()`](ArrayBase::argsort_axis), so elements that are not
+    /// comparable to themselves, like NaN, get the greatest ranks, and they are
+    /// never equal to each other.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, TieMethod};
+    ///
+    /// let a = array![10, 30, 20, 20];
+    /// assert_eq!(a.rank_axis(Axis(0), TieMethod::Average), array![1., 4., 2.5, 2.5]);
+    /// assert_eq!(a.rank_axis(Axis(0), TieMethod::Min), array![1., 4., 2., 2.]);
+    /// assert_eq!(a.rank_axis(Axis(0), TieMethod::Max), array![1., 4., 3., 3.]);
+    /// assert_eq!(a.rank_axis(Axis(0), TieMethod::Dense), array![1., 3., 2., 2.]);
+    /// assert_eq!(a.rank_axis(Axis(0), TieMethod::Ordinal), array![1., 4., 2., 3.]);
+    /// ```
+    pub fn rank_axis(&self, axis: Axis, tie: TieMethod) -> Array<f64, D>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        let order = self.argsort_axis(axis);
+        let mut ranks = Array::zeros(self.raw_dim());
+        Zip::from(self.lanes(axis))
+            .and(order.lanes(axis))
+            .and(ranks.lanes_mut(axis))
+            .for_each(|lane, order, mut ranks| {
+                // visit the groups of equal elements, at positions start..end
+                // in sorted order
+                let mut start = 0;
+                let mut dense_rank = 0;
+                while start < n {
+                    let first = &lane[order[start]];
+                    let mut end = start + 1;
+                    while end < n && lane[order[end]].partial_cmp(first) == Some(Ordering::Equal) {
+                        end += 1;
+                    }
+                    dense_rank += 1;
+                    for pos in start..end {
+                        ranks[order[pos]] = match tie {
+                            TieMethod::Average => (start + 1 + end) as f64 / 2.,
+                            TieMethod::Min => (start + 1) as f64,
+                            TieMethod::Max => end as f64,
+                            TieMethod::Dense => dense_rank as f64,
+                            TieMethod::Ordinal => (pos + 1) as f64,
+                        };
+                    }
+                    start = end;
+                }
+            });
+        ranks
+    }
+
+    /// Return the numerical derivative of the array along `axis`, where
+    /// consecutive elements along the axis are `spacing` apart.
+    ///
+    /// Like NumPy's `gradient`, the derivative is computed with central
+    /// differences `(f[i + 1] - f[i - 1]) / (2 * spacing)` at the interior
+    /// points, and with one-sided differences at the first and the last point.
+    /// The result has the same shape as `self`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is less than two.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let f = array![1., 2., 4., 7., 11.];
+    /// assert_eq!(f.gradient(Axis(0), 1.), array![1., 1.5, 2.5, 3.5, 4.]);
+    /// assert_eq!(f.gradient(Axis(0), 0.5), array![2., 3., 5., 7., 8.]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn gradient(&self, axis: Axis, spacing: A) -> Array<A, D>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        assert!(
+            n >= 2,
+            "ndarray: gradient: the length of the axis must be at least 2, got {}",
+            n
+        );
+        let two_spacing = spacing + spacing;
+        let mut result = Array::zeros(self.raw_dim());
+        Zip::from(self.lanes(axis))
+            .and(result.lanes_mut(axis))
+            .for_each(|f, mut df| {
+                df[0] = (f[1] - f[0]) / spacing;
+                for i in 1..n - 1 {
+                    df[i] = (f[i + 1] - f[i - 1]) / two_spacing;
+                }
+                df[n - 1] = (f[n - 1] - f[n - 2]) / spacing;
+            });
+        result
+    }
+
+    /// Return `true` if the arrays' elementwise differences are all within
+    /// the given absolute tolerance, `false` otherwise.
+    ///
+    /// A NaN element is never close to any element; use
+    /// [`all_close_with`](ArrayBase::all_close_with) to treat NaNs in the same
+    /// position as equal.
+    ///
+    /// The comparison stops at the first pair of elements that are not close.
+    ///
+    /// **Panics** if the shapes of the arrays are not equal.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let b = array![[1.001, 2.], [3., 3.999]];
+    /// assert!(a.all_close(&b, 1e-2));
+    /// assert!(!a.all_close(&b, 1e-4));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn all_close<S2>(&self, rhs: &ArrayBase<S2, D>, tol: A) -> bool
+    where
+        A: Float,
+        S2: Data<Elem = A>,
+    {
+        self.all_close_with(rhs, tol, A::zero(), false)
+    }
+
+    /// Return `true` if the arrays are elementwise equal within an absolute
+    /// tolerance `atol` and a relative tolerance `rtol`, `false` otherwise.
+    ///
+    /// Like NumPy's `allclose`, elements `a` (from `self`) and `b` (from `rhs`)
+    /// are considered close if
+    ///
+    /// ```text
+    /// |a - b| <= atol + rtol * |b|
+    /// ```
+    ///
+    /// Note that the test is not symmetric in `a` and `b`. Infinite elements
+    /// are only close if they are equal. If `equal_nan` is `true`, two NaNs in
+    /// the same position are considered close; otherwise, a NaN element is
+    /// never close to any element.
+    ///
+    /// The comparison stops at the first pair of elements that are not close.
+    ///
+    /// **Panics** if the shapes of the arrays are not equal.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1e6, 1e-6, f64::NAN];
+    /// let b = array![1.000_001e6, 0., f64::NAN];
+    /// assert!(a.all_close_with(&b, 1e-5, 1e-5, true));
+    /// assert!(!a.all_close_with(&b, 1e-5, 1e-5, false));
+    /// assert!(!a.all_close_with(&b, 1e-5, 0., true));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn all_close_with<S2>(&self, rhs: &ArrayBase<S2, D>, atol: A, rtol: A, equal_nan: bool)
+        -> bool
+    where
+        A: Float,
+        S2: Data<Elem = A>,
+    {
+        assert!(
+            self.shape() == rhs.shape(),
+            "ndarray: arrays of shapes {:?} and {:?} can not be compared",
+            self.shape(),
+            rhs.shape()
+        );
+        crate::Zip::from(self).and(rhs).all(|&a, &b| {
+            if a.is_nan() || b.is_nan() {
+                equal_nan && a.is_nan() && b.is_nan()
+            } else if a.is_infinite() || b.is_infinite() {
+                a == b
+            } else {
+                (a - b).abs() <= atol + rtol * b.abs()
+            }
+        })
+    }
+
+    /// Return a copy of the array with NaN replaced by `nan`, positive
+    /// infinity by `posinf` and negative infinity by `neginf`.
+    ///
+    /// Finite elements are copied unchanged.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+    /// assert_eq!(a.nan_to_num(0., f64::MAX, f64::MIN),
+    ///            array![1., 0., f64::MAX, f64::MIN]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn nan_to_num(&self, nan: A, posinf: A, neginf: A) -> Array<A, D>
+    where
+        A: Float,
+    {
+        self.mapv(|x| numeric_util::nan_to_num(x, nan, posinf, neginf))
+    }
+
+    /// Replace NaN by `nan`, positive infinity by `posinf` and negative
+    /// infinity by `neginf`, in place.
+    ///
+    /// See also [`.nan_to_num()`](Self::nan_to_num).
+    #[cfg(feature = "std")]
+    pub fn nan_to_num_inplace(&mut self, nan: A, posinf: A, neginf: A)
+    where
+        A: Float,
+        S: DataMut,
+    {
+        self.mapv_inplace(|x| numeric_util::nan_to_num(x, nan, posinf, neginf))
+    }
+
+    /// Return a copy of the array rescaled linearly to the range `[0, 1]`,
+    /// so that its least element becomes 0 and its greatest becomes 1.
+    ///
+    /// NaN elements are ignored when finding the least and greatest element,
+    /// and stay NaN. If all elements are equal, the result is all zeros.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![2., 4., 3., 6.];
+    /// assert_eq!(a.normalize(), array![0., 0.5, 0.25, 1.]);
+    /// assert_eq!(array![7., 7.].normalize(), array![0., 0.]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn normalize(&self) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let (min, max) = match (self.min_skipnan(), self.max_skipnan()) {
+            (Some(&min), Some(&max)) => (min, max),
+            // empty or all NaN
+            _ => return self.to_owned(),
+        };
+        let range = max - min;
+        if range > A::zero() {
+            self.mapv(|x| (x - min) / range)
+        } else {
+            self.mapv(|x| x - min)
+        }
+    }
+
+    /// Return the Euclidean (L2) norm of the array, the square root of the sum
+    /// of the squares of the elements.
+    ///
+    /// Like the other whole-array norms, this treats the elements as one vector
+    /// regardless of the shape of the array, so for a matrix it is the Frobenius
+    /// norm. The elements are visited once, in an arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3., 0.], [0., -4.]];
+    /// assert_eq!(a.norm_l2(), 5.);
+    /// assert_eq!(a.norm_l2_sq(), 25.);
+    /// assert_eq!(a.norm_l1(), 7.);
+    /// assert_eq!(a.norm_max(), 4.);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn norm_l2(&self) -> A
+    where
+        A: Float,
+    {
+        self.norm_l2_sq().sqrt()
+    }
+
+    /// Return the square of the Euclidean (L2) norm of the array, the sum of
+    /// the squares of the elements, without taking the square root.
+    ///
+    /// See [`.norm_l2()`](Self::norm_l2).
+    #[cfg(feature = "std")]
+    pub fn norm_l2_sq(&self) -> A
+    where
+        A: Float,
+    {
+        self.fold(A::zero(), |acc, &x| acc + x * x)
+    }
+
+    /// Return the L1 norm of the array, the sum of the absolute values of the
+    /// elements.
+    ///
+    /// See [`.norm_l2()`](Self::norm_l2).
+    #[cfg(feature = "std")]
+    pub fn norm_l1(&self) -> A
+    where
+        A: Float,
+    {
+        self.fold(A::zero(), |acc, &x| acc + x.abs())
+    }
+
+    /// Return the maximum norm of the array, the greatest absolute value of
+    /// the elements, or zero if the array is empty.
+    ///
+    /// Like the other norms, this is NaN if any element is NaN.
+    /// See [`.norm_l2()`](Self::norm_l2).
+    #[cfg(feature = "std")]
+    pub fn norm_max(&self) -> A
+    where
+        A: Float,
+    {
+        // once the accumulator is NaN, no comparison replaces it
+        self.fold(A::zero(), |acc, &x| {
+            let x = x.abs();
+            if x > acc || x.is_nan() {
+                x
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Return a copy of the array with each lane along `axis` divided by its
+    /// norm, so that each lane has unit norm.
+    ///
+    /// The norm is selected by `kind`: the sum of absolute values
+    /// ([`NormKind::L1`]; with nonnegative elements, this makes each lane sum to
+    /// one), the Euclidean norm ([`NormKind::L2`]) or the greatest absolute
+    /// value ([`NormKind::Max`]).
+    ///
+    /// Lanes with zero norm are all zeros, and stay zeros. Lanes with a NaN norm
+    /// are copied unchanged.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, NormKind};
+    ///
+    /// let a = array![[1., 3.],
+    ///                [0., 0.],
+    ///                [3., -4.]];
+    /// assert_eq!(a.normalize_axis(Axis(1), NormKind::L1),
+    ///            array![[0.25, 0.75], [0., 0.], [3. / 7., -4. / 7.]]);
+    /// assert_eq!(a.normalize_axis(Axis(1), NormKind::Max),
+    ///            array![[1. / 3., 1.], [0., 0.], [0.75, -1.]]);
+    /// assert_eq!(a.normalize_axis(Axis(0), NormKind::L2).row(2),
+    ///            array![3. / 10f64.sqrt(), -4. / 5.]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn normalize_axis(&self, axis: Axis, kind: NormKind) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let mut result = self.to_owned();
+        for mut lane in result.lanes_mut(axis) {
+            let norm = match kind {
+                NormKind::L1 => lane.fold(A::zero(), |acc, x| acc + x.abs()),
+                NormKind::L2 => lane.fold(A::zero(), |acc, &x| acc + x * x).sqrt(),
+                NormKind::Max => lane.norm_max(),
+            };
+            if norm > A::zero() {
+                lane.mapv_inplace(|x| x / norm);
+            }
+        }
+        result
+    }
+
+    /// Return the softmax of each lane along `axis`.
+    ///
+    /// Each element `x` of a lane is replaced by `exp(x) / sum(exp(y))`, where
+    /// the sum is over the elements `y` of the lane, so each lane of the result
+    /// is positive and sums to one. For numerical stability the greatest element
+    /// of the lane is subtracted before exponentiating, which does not change
+    /// the result but avoids overflow for large elements.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 1.],
+    ///                [0., 1000.]];
+    /// let s = a.softmax_axis(Axis(1));
+    /// assert_eq!(s, array![[0.5, 0.5],
+    ///                      [0., 1.]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn softmax_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let mut result = self.to_owned();
+        for mut lane in result.lanes_mut(axis) {
+            let max = lane.fold(A::neg_infinity(), |acc, &x| acc.max(x));
+            lane.mapv_inplace(|x| (x - max).exp());
+            let sum = lane.sum();
+            lane.mapv_inplace(|x| x / sum);
+        }
+        result
+    }
+
+    /// Return a copy of the array standardized to zero mean and unit standard
+    /// deviation, by subtracting the mean and dividing by the (population)
+    /// standard deviation, [`.std(0)`](Self::std).
+    ///
+    /// If all elements are equal, the result is all zeros.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 3., 5., 7.];
+    /// let s = a.standardize();
+    /// assert!(s.all_close(&(array![-3., -1., 1., 3.] / 5f64.sqrt()), 1e-12));
+    /// assert!(s.mean().unwrap().abs() < 1e-12);
+    /// assert!((s.std(0.) - 1.).abs() < 1e-12);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn standardize(&self) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mean = match self.mean() {
+            Some(mean) => mean,
+            None => return self.to_owned(),
+        };
+        let std = self.std(A::zero());
+        if std > A::zero() {
+            self.mapv(|x| (x - mean) / std)
+        } else {
+            self.mapv(|x| x - mean)
+        }
+    }
+}
+
+/// Return the value at position `h` (with `0. <= h <= buf.len() - 1`) of
+/// `buf` sorted in increasing order, using `method` for fractional positions;
+/// the order of `buf` is changed. Return NaN if `buf` contains NaN.
+#[cfg(feature = "std")]
+fn quantile_of_buffer<A>(buf: &mut [A], h: f64, method: QuantileMethod) -> A
+where
+    A: Float + FromPrimitive,
+{
+    if buf.iter().any(|x| x.is_nan()) {
+        return A::nan();
+    }
+    let lower = h.floor() as usize;
+    let frac = h - lower as f64;
+    let index = match method {
+        QuantileMethod::Lower => lower,
+        QuantileMethod::Higher => h.ceil() as usize,
+        QuantileMethod::Nearest => {
+            if frac > 0.5 || (frac == 0.5 && lower % 2 == 1) {
+                lower + 1
+            } else {
+                lower
+            }
+        }
+        QuantileMethod::Linear | QuantileMethod::Midpoint => {
+            if frac == 0. {
+                lower
+            } else {
+                let cmp = |a: &A, b: &A| a.partial_cmp(b).unwrap();
+                let (_, &mut low, above) = buf.select_nth_unstable_by(lower, cmp);
+                // the next element in sorted order is the least one above
+                let high = above.iter().cloned().fold(A::infinity(), A::min);
+                return match method {
+                    QuantileMethod::Midpoint => (low + high) / A::from_f64(2.).unwrap(),
+                    _ => low + A::from_f64(frac).unwrap() * (high - low),
+                };
+            }
+        }
+    };
+    let (_, &mut value, _) = buf.select_nth_unstable_by(index, |a, b| a.partial_cmp(b).unwrap());
+    value
+}
+
+/// Return the part selected by `mode` of the full convolution of `a` and `v`,
+/// in reverse order if `reverse` is true.
+///
+/// **Panics** if `a` or `v` is empty; the message names `method`.
+fn sliding_sum_of_products<A>(
+    method: &str,
+    a: ArrayView1<'_, A>,
+    v: ArrayView1<'_, A>,
+    mode: ConvMode,
+    reverse: bool,
+) -> Array1<A>
+where
+    A: Clone + Zero + Mul<Output = A>,
+{
+    let (n, m) = (a.len(), v.len());
+    assert!(n > 0 && m > 0, "ndarray: {}: the inputs must not be empty", method);
+    let (short, long) = (Ord::min(n, m), Ord::max(n, m));
+    let (start, len) = match mode {
+        ConvMode::Full => (0, n + m - 1),
+        ConvMode::Same => ((short - 1) / 2, long),
+        ConvMode::Valid => (short - 1, long - short + 1),
+    };
+    Array1::from_shape_fn(len, |i| {
+        let k = if reverse { start + len - 1 - i } else { start + i };
+        // the indices j into `v` for which k - j is an index into `a`
+        let j_range = k.saturating_sub(n - 1)..=Ord::min(k, m - 1);
+        j_range.fold(A::zero(), |acc, j| acc + a[k - j].clone() * v[j].clone())
+    })
+}
+
+/// # Numerical Methods for 1-D Arrays
+impl<A, S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    /// Count the elements in `bins` evenly spaced bins over `range`, and
+    /// return the counts and the bin edges.
+    ///
+    /// The edges are `bins + 1` evenly spaced values from `range.0` to
+    /// `range.1`. Bin `i` holds the elements `x` with `edges[i] <= x < edges[i + 1]`,
+    /// except that the last bin is closed and also holds the elements equal to
+    /// `range.1`, like in NumPy. Elements outside of the range, and NaN, are not
+    /// counted.
+    ///
+    /// **Panics** if `bins` is zero, or if `range.0 < range.1` is not true for
+    /// finite values.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0.5, 1., 1.5, 2.2, 3., 3.5, -1., f64::NAN];
+    /// let (counts, edges) = a.histogram(3, (0., 3.));
+    /// assert_eq!(counts, array![1, 2, 2]);
+    /// assert_eq!(edges, array![0., 1., 2., 3.]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn histogram(&self, bins: usize, range: (A, A)) -> (Array1<usize>, Array1<A>)
+    where
+        A: Float,
+    {
+        let (low, high) = range;
+        assert!(bins > 0, "ndarray: histogram: the number of bins must be nonzero");
+        assert!(
+            low.is_finite() && high.is_finite() && low < high,
+            "ndarray: histogram: the range must be finite and increasing"
+        );
+        // work with halved values, so that a range as wide as (-MAX, MAX) does not overflow;
+        // for other ranges the edges are the same as those of `linspace`
+        let two = A::one() + A::one();
+        let half_low = low / two;
+        let half_width = high / two - half_low;
+        let half_step = half_width / A::from(bins).unwrap();
+        let edges = Array1::from_shape_fn(bins + 1, |i| {
+            if i == bins {
+                high
+            } else {
+                (half_low + half_step * A::from(i).unwrap()) * two
+            }
+        });
+        let mut counts = Array1::zeros(bins);
+        let scale = A::from(bins).unwrap() / half_width;
+        for &x in self {
+            if !(x >= low && x <= high) {
+                continue;
+            }
+            let mut bin = Ord::min(((x / two - half_low) * scale).to_usize().unwrap_or(0), bins - 1);
+            // correct for rounding, so that the bins agree with the edges
+            if x < edges[bin] {
+                bin -= 1;
+            } else if bin + 1 < bins && x >= edges[bin + 1] {
+                bin += 1;
+            }
+            counts[bin] += 1;
+        }
+        (counts, edges)
+    }
+
+    /// Return the discrete linear convolution of the array with `kernel`, like
+    /// NumPy's `convolve`.
+    ///
+    /// Element `k` of the full convolution of `a` (of length `n`) and `v` (of
+    /// length `m`) is the sum of `a[k - j] * v[j]` over all `j` where both
+    /// indices are in bounds, for `k` in `0..n + m - 1`; `mode` selects which
+    /// part of it is returned:
+    ///
+    /// - `ConvMode::Full`: all of it, of length `n + m - 1`
+    /// - `ConvMode::Same`: the middle part of length `max(n, m)`
+    /// - `ConvMode::Valid`: the part where the inputs overlap completely, of
+    ///   length `max(n, m) - min(n, m) + 1`
+    ///
+    /// Each element is computed directly as a sum of products, so the cost is
+    /// proportional to `n * m`.
+    ///
+    /// **Panics** if the array or `kernel` is empty.
+    ///
+    /// ```
+    /// use ndarray::{array, ConvMode};
+    ///
+    /// let a = array![1, 2, 3];
+    /// let v = array![0, 1, 2];
+    /// assert_eq!(a.convolve(&v, ConvMode::Full), array![0, 1, 4, 7, 6]);
+    /// assert_eq!(a.convolve(&v, ConvMode::Same), array![1, 4, 7]);
+    /// assert_eq!(a.convolve(&v, ConvMode::Valid), array![4]);
+    /// ```
+    pub fn convolve<S2>(&self, kernel: &ArrayBase<S2, Ix1>, mode: ConvMode) -> Array1<A>
+    where
+        A: Clone + Zero + Mul<Output = A>,
+        S2: Data<Elem = A>,
+    {
+        sliding_sum_of_products("convolve", self.view(), kernel.view(), mode, false)
+    }
+
+    /// Return the cross-correlation of the array with `other`, like NumPy's
+    /// `correlate` for real numbers.
+    ///
+    /// This is the [convolution](ArrayBase::convolve) with `other` reversed:
+    /// element `k` of the full cross-correlation of `a` and `v` (of length `m`)
+    /// is the sum of `a[i] * v[j]` over the index pairs with
+    /// `i - j = k - (m - 1)`, the lag. `mode` selects the part that is
+    /// returned and has the same output lengths as for `.convolve()`. The
+    /// elements of `other` are not conjugated.
+    ///
+    /// Like NumPy, if `other` is longer than the array, this computes
+    /// `other.correlate(self, mode)` reversed. That changes the result only for
+    /// `ConvMode::Same` with an even shorter length: the part is then taken one
+    /// element later from the full cross-correlation.
+    ///
+    /// **Panics** if the array or `other` is empty.
+    ///
+    /// ```
+    /// use ndarray::{array, ConvMode};
+    ///
+    /// let a = array![1, 2, 3];
+    /// let v = array![0, 1, 2];
+    /// assert_eq!(a.correlate(&v, ConvMode::Full), array![2, 5, 8, 3, 0]);
+    /// assert_eq!(a.correlate(&v, ConvMode::Valid), array![8]);
+    ///
+    /// let w = array![1, 2, 3, 4];
+    /// assert_eq!(array![1, 2].correlate(&w, ConvMode::Full), array![4, 11, 8, 5, 2]);
+    /// assert_eq!(array![1, 2].correlate(&w, ConvMode::Same), array![11, 8, 5, 2]);
+    /// ```
+    pub fn correlate<S2>(&self, other: &ArrayBase<S2, Ix1>, mode: ConvMode) -> Array1<A>
+    where
+        A: Clone + Zero + Mul<Output = A>,
+        S2: Data<Elem = A>,
+    {
+        if self.len() < other.len() {
+            let mut reversed = self.view();
+            reversed.invert_axis(Axis(0));
+            sliding_sum_of_products("correlate", other.view(), reversed, mode, true)
+        } else {
+            let mut reversed = other.view();
+            reversed.invert_axis(Axis(0));
+            sliding_sum_of_products("correlate", self.view(), reversed, mode, false)
+        }
+    }
+}
+
+/// # Counting Methods for 1-D Arrays of Indices
+impl<S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = usize>,
+{
+    /// Count the occurrences of each value in the array.
+    ///
+    /// Element `i` of the result is the number of elements equal to `i`. The
+    /// result has length `max + 1`, where `max` is the greatest element, or
+    /// `min_length` if that is longer. This is NumPy's `bincount`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array![1, 3, 1, 0, 3, 3];
+    /// assert_eq!(labels.bincount(0), array![1, 2, 0, 3]);
+    /// assert_eq!(labels.bincount(6), array![1, 2, 0, 3, 0, 0]);
+    /// ```
+    pub fn bincount(&self, min_length: usize) -> Array1<usize> {
+        let mut counts = Array1::zeros(self.bincount_len(min_length));
+        for &i in self {
+            counts[i] += 1;
+        }
+        counts
+    }
+
+    /// Sum the weights of the occurrences of each value in the array.
+    ///
+    /// Element `i` of the result is the sum of `weights[j]` for all `j` where
+    /// `self[j] == i`, or zero if there are none. The length of the result is
+    /// like for [`.bincount()`](Self::bincount).
+    ///
+    /// **Panics** if `weights` has a different length than `self`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array![1, 3, 1, 0];
+    /// let weights = array![0.5, 1., 0.25, 2.];
+    /// assert_eq!(labels.bincount_weighted(&weights, 0), array![2., 0.75, 0., 1.]);
+    /// ```
+    pub fn bincount_weighted<W, S2>(&self, weights: &ArrayBase<S2, Ix1>, min_length: usize) -> Array1<W>
+    where
+        W: Clone + Add<Output = W> + Zero,
+        S2: Data<Elem = W>,
+    {
+        assert_eq!(
+            self.len(),
+            weights.len(),
+            "ndarray: bincount_weighted: the weights must have the same length as the array"
+        );
+        let mut sums = Array1::<W>::zeros(self.bincount_len(min_length));
+        for (&i, w) in self.iter().zip(weights) {
+            sums[i] = sums[i].clone() + w.clone();
+        }
+        sums
+    }
+
+    fn bincount_len(&self, min_length: usize) -> usize {
+        self.iter().map(|&i| i + 1).fold(min_length, Ord::max)
+    }
+}
+
+/// # Pooling Methods for 2-D Arrays
+///
+/// The pooling methods reduce each `(ph, pw)` window of the array to a single
+/// value. The windows start at every `stride`-th row and column (see
+/// [`.windows_with_stride()`](ArrayBase::windows_with_stride)), so they overlap
+/// if `stride` is less than the window size, and elements are skipped if it is
+/// greater. There is no padding: only windows that fit completely in the array
+/// are used, so the result has
+///
+/// ```text
+/// (nrows - ph) / stride + 1  rows  and  (ncols - pw) / stride + 1  columns
+/// ```
+///
+/// (rounded down), or zero rows or columns if the window doesn't fit.
+///
+/// **Panics** if any of `ph`, `pw` or `stride` is zero.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the greatest element of each window.
+    ///
+    /// If a window contains an element that is not comparable to itself, such
+    /// as NaN, the result for that window is that element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 5, 2, 0],
+    ///                [3, 4, 8, 1],
+    ///                [0, 2, 6, 7]];
+    /// assert_eq!(a.max_pool2d((2, 2), 2), array![[5, 8]]);
+    /// assert_eq!(a.max_pool2d((2, 2), 1), array![[5, 8, 8],
+    ///                                             [4, 8, 8]]);
+    /// ```
+    pub fn max_pool2d(&self, (ph, pw): (usize, usize), stride: usize) -> Array2<A>
+    where
+        A: Clone + PartialOrd,
+    {
+        Zip::from(self.windows_with_stride((ph, pw), (stride, stride))).map_collect(|window| {
+            let mut max = &window[[0, 0]];
+            for elt in &window {
+                if elt.partial_cmp(elt).is_none() {
+                    return elt.clone();
+                }
+                if elt > max {
+                    max = elt;
+                }
+            }
+            max.clone()
+        })
+    }
+
+    /// Return the mean of each window.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 5., 2., 0.],
+    ///                [3., 4., 8., 1.],
+    ///                [0., 2., 6., 7.]];
+    /// assert_eq!(a.avg_pool2d((2, 2), 2), array![[3.25, 2.75]]);
+    /// assert_eq!(a.avg_pool2d((3, 3), 1), array![[31. / 9., 35. / 9.]]);
+    /// ```
+    pub fn avg_pool2d(&self, (ph, pw): (usize, usize), stride: usize) -> Array2<A>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+    {
+        Zip::from(self.windows_with_stride((ph, pw), (stride, stride)))
+            .map_collect(|window| window.mean().unwrap())
+    }
+}
+
+/// # Statistical Methods for 2-D Arrays
+///
+/// These methods treat each row of the array as a variable and each column as
+/// an observation of all the variables, like NumPy's `cov` and `corrcoef`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the covariance matrix of the variables in the rows of the array,
+    /// whose columns are observations.
+    ///
+    /// The element at `(i, j)` of the result is the covariance of the rows
+    /// `i` and `j`:
+    ///
+    /// ```text
+    ///                  1       n
+    /// cov(x, y) = ――――――――   ∑ (xₖ - x̅)(yₖ - y̅)
+    ///             n - ddof  k=1
+    /// ```
+    ///
+    /// where `n` is the number of observations (columns) and `x̅` and `y̅` are
+    /// the means of the rows; so its diagonal holds the variances of the rows.
+    /// Use `ddof = 1` for the unbiased sample covariance and `ddof = 0` for the
+    /// population covariance.
+    ///
+    /// **Panics** if there are fewer than two observations, or if `ddof` is
+    /// less than zero or not less than the number of observations.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // two variables, four observations
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [2., 0., 6., 4.]];
+    /// assert_eq!(a.cov(1.), array![[5. / 3., 2.],
+    ///                              [2., 20. / 3.]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cov(&self, ddof: A) -> Array2<A>
+    where
+        A: Float + FromPrimitive + 'static,
+    {
+        let n_obs = self.ncols();
+        assert!(n_obs >= 2, "ndarray: cov: at least two observations are required, got {}", n_obs);
+        let n = A::from_usize(n_obs).expect("Converting length to `A` must not fail.");
+        assert!(
+            ddof >= A::zero() && ddof < n,
+            "ndarray: cov: `ddof` must not be less than zero and must be less than the number of observations"
+        );
+        let dof = n - ddof;
+        let mean = self.mean_axis(Axis(1)).unwrap();
+        let centered = self - &mean.insert_axis(Axis(1));
+        let mut cov = centered.dot(&centered.t());
+        cov.mapv_inplace(|x| x / dof);
+        cov
+    }
+
+    /// Return the matrix of Pearson correlation coefficients of the variables
+    /// in the rows of the array, whose columns are observations.
+    ///
+    /// This is the [covariance matrix](ArrayBase::cov) normalized so that the
+    /// element at `(i, j)` is `cov[i, j] / sqrt(cov[i, i] * cov[j, j])`,
+    /// clipped to `-1. ..= 1.`; its diagonal is one, up to rounding. The rows
+    /// and columns of a variable without variance are NaN.
+    ///
+    /// **Panics** if there are fewer than two observations.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [8., 6., 4., 2.]];
+    /// assert_eq!(a.corrcoef(), array![[1., -1.], [-1., 1.]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn corrcoef(&self) -> Array2<A>
+    where
+        A: Float + FromPrimitive + 'static,
+    {
+        let mut corr = self.cov(A::zero());
+        let std_dev = corr.diag().mapv(A::sqrt);
+        let one = A::one();
+        Zip::indexed(&mut corr).for_each(|(i, j), c| {
+            let r = *c / std_dev[i] / std_dev[j];
+            *c = if r > one {
+                one
+            } else if r < -one {
+                -one
+            } else {
+                r
+            };
+        });
+        corr
+    }
 }
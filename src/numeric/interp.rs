@@ -0,0 +1,123 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+
+/// Interpolate linearly between the points `(xp[i], fp[i])` at each of the
+/// positions in `x`, like NumPy's `interp`.
+///
+/// `xp` must be in increasing order (equal values are allowed). Positions
+/// below `xp[0]` get the value `fp[0]`, positions above the last point get the
+/// last value of `fp`, and NaN positions get NaN; see [`interp_with`] to use
+/// other values outside of the points. Each position is looked up with a
+/// binary search.
+///
+/// ***Errors*** if `xp` and `fp` have different lengths or are empty
+/// (`IncompatibleShape`), or if `xp` is not in increasing order or contains
+/// NaN (`Unsupported`).
+///
+/// ```
+/// use ndarray::{array, interp};
+///
+/// let xp = array![0., 1., 3.];
+/// let fp = array![10., 20., 0.];
+/// let y = interp(&array![-1., 0.5, 2., 3., 9.], &xp, &fp).unwrap();
+/// assert_eq!(y, array![10., 15., 10., 0., 0.]);
+/// ```
+pub fn interp<S1, S2, S3>(
+    x: &ArrayBase<S1, Ix1>,
+    xp: &ArrayBase<S2, Ix1>,
+    fp: &ArrayBase<S3, Ix1>,
+) -> Result<Array1<f64>, ShapeError>
+where
+    S1: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    S3: Data<Elem = f64>,
+{
+    interp_impl(x, xp, fp, None, None)
+}
+
+/// Interpolate linearly between the points `(xp[i], fp[i])` at each of the
+/// positions in `x`, using `left` and `right` for the positions below and
+/// above the points.
+///
+/// See [`interp`] for the requirements on `xp` and `fp`.
+///
+/// ***Errors*** like [`interp`].
+///
+/// ```
+/// use ndarray::{array, interp_with};
+///
+/// let y = interp_with(&array![-1., 0.5, 2.], &array![0., 1.], &array![0., 2.], f64::NAN, 5.).unwrap();
+/// assert!(y[0].is_nan());
+/// assert_eq!(y.slice(ndarray::s![1..]), array![1., 5.]);
+/// ```
+pub fn interp_with<S1, S2, S3>(
+    x: &ArrayBase<S1, Ix1>,
+    xp: &ArrayBase<S2, Ix1>,
+    fp: &ArrayBase<S3, Ix1>,
+    left: f64,
+    right: f64,
+) -> Result<Array1<f64>, ShapeError>
+where
+    S1: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    S3: Data<Elem = f64>,
+{
+    interp_impl(x, xp, fp, Some(left), Some(right))
+}
+
+fn interp_impl<S1, S2, S3>(
+    x: &ArrayBase<S1, Ix1>,
+    xp: &ArrayBase<S2, Ix1>,
+    fp: &ArrayBase<S3, Ix1>,
+    left: Option<f64>,
+    right: Option<f64>,
+) -> Result<Array1<f64>, ShapeError>
+where
+    S1: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+    S3: Data<Elem = f64>,
+{
+    if xp.is_empty() || xp.len() != fp.len() {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    // contiguous copies for the binary search
+    let xp: Vec<f64> = xp.to_vec();
+    let fp: Vec<f64> = fp.to_vec();
+    if xp.iter().any(|x| x.is_nan()) || xp.windows(2).any(|w| w[0] > w[1]) {
+        return Err(from_kind(ErrorKind::Unsupported));
+    }
+    let last = xp.len() - 1;
+    let left = left.unwrap_or(fp[0]);
+    let right = right.unwrap_or(fp[last]);
+    Ok(x.mapv(|x| {
+        if x.is_nan() {
+            x
+        } else if x < xp[0] {
+            left
+        } else if x > xp[last] {
+            right
+        } else {
+            // xp[i - 1] <= x < xp[i], where 1 <= i
+            let i = xp
+                .binary_search_by(|&p| if p <= x { Ordering::Less } else { Ordering::Greater })
+                .unwrap_or_else(|i| i);
+            if i > last {
+                fp[last]
+            } else {
+                let t = (x - xp[i - 1]) / (xp[i] - xp[i - 1]);
+                fp[i - 1] + t * (fp[i] - fp[i - 1])
+            }
+        }
+    }))
+}
@@ -1 +1,69 @@
 mod impl_numeric;
+mod interp;
+
+pub use self::interp::{interp, interp_with};
+
+/// The vector norm to use in [`.normalize_axis()`](crate::ArrayBase::normalize_axis).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NormKind {
+    /// The sum of the absolute values
+    L1,
+    /// The square root of the sum of the squares (the Euclidean norm)
+    L2,
+    /// The greatest absolute value
+    Max,
+}
+
+/// How [`.quantile_axis()`](crate::ArrayBase::quantile_axis) chooses a value
+/// when the quantile falls between two elements of the sorted lane, at the
+/// fractional position `h` between `lower` and `higher`. The names are those
+/// used by NumPy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QuantileMethod {
+    /// Interpolate linearly: `lower + (h - floor(h)) * (higher - lower)`
+    Linear,
+    /// Use `lower`
+    Lower,
+    /// Use `higher`
+    Higher,
+    /// Use the nearer of `lower` and `higher`, and the one with the even
+    /// index if they are equally near
+    Nearest,
+    /// Use `(lower + higher) / 2`
+    Midpoint,
+}
+
+/// How [`.rank_axis()`](crate::ArrayBase::rank_axis) ranks elements that are
+/// equal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TieMethod {
+    /// The mean of the ranks the equal elements would get if they were distinct
+    Average,
+    /// The least of the ranks the equal elements would get if they were distinct
+    Min,
+    /// The greatest of the ranks the equal elements would get if they were distinct
+    Max,
+    /// Like `Min`, but the next greater element gets the next rank, so there
+    /// are no gaps between the ranks
+    Dense,
+    /// Distinct ranks, in order of increasing index along the axis
+    Ordinal,
+}
+
+/// The part of the result computed by [`.convolve()`](crate::ArrayBase::convolve)
+/// and [`.correlate()`](crate::ArrayBase::correlate), for inputs of lengths `n`
+/// and `m`. The names are those used by NumPy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConvMode {
+    /// Every position where the inputs overlap; the length is `n + m - 1`
+    Full,
+    /// The middle part of `Full`, of length `max(n, m)`
+    Same,
+    /// Only the positions where the inputs overlap completely; the length is
+    /// `max(n, m) - min(n, m) + 1`
+    Valid,
+}
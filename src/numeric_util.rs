@@ -8,6 +8,9 @@
 
 use std::cmp;
 
+#[cfg(feature = "std")]
+use num_traits::Float;
+
 use crate::LinalgScalar;
 
 /// Fold over the manually unrolled `xs` with `f`
@@ -146,3 +149,17 @@ where
 
     true
 }
+
+/// Replace `x` by `nan`, `posinf` or `neginf` if it is NaN or infinite.
+#[cfg(feature = "std")]
+pub fn nan_to_num<A: Float>(x: A, nan: A, posinf: A, neginf: A) -> A {
+    if x.is_nan() {
+        nan
+    } else if x == A::infinity() {
+        posinf
+    } else if x == A::neg_infinity() {
+        neginf
+    } else {
+        x
+    }
+}
@@ -1,11 +1,15 @@
-use crate::{Array, ArrayBase, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{Array, ArrayBase, Data, DataMut, Dimension, FoldWhile, IntoNdProducer, NdProducer, ShapeBuilder, Zip};
+use crate::dimension::size_of_shape_checked;
 use crate::AssignElem;
+use crate::numeric_util::nan_to_num;
+use num_traits::Float;
 
 use crate::parallel::prelude::*;
 use crate::parallel::par::ParallelSplits;
 use super::send_producer::SendProducer;
 
 use crate::partial::Partial;
+use std::mem::MaybeUninit;
 
 /// # Parallel methods
 ///
@@ -43,8 +47,258 @@ where
             .into_par_iter()
             .for_each(move |x| *x = f(x.clone()))
     }
+
+    /// Fallible parallel version of `map_inplace`.
+    ///
+    /// Modify the array in place by calling `f` by mutable reference on each element,
+    /// stopping early if any call returns an error.
+    ///
+    /// Elements are visited in arbitrary order. When an error occurs, no new
+    /// calls to `f` are started, but calls already running on other threads
+    /// are completed; so some elements may have been modified and others not.
+    /// If more than one call returns an error, it is unspecified which of the
+    /// errors is returned.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let mut a = Array::from_shape_fn((4, 4), |(i, j)| (i * j) as f64);
+    /// let r = a.par_try_map_inplace(|x| {
+    ///     if *x > 10. {
+    ///         return Err("element too large");
+    ///     }
+    ///     *x = x.sqrt();
+    ///     Ok(())
+    /// });
+    /// assert_eq!(r, Ok(()));
+    /// assert_eq!(a[[3, 3]], 3.);
+    /// ```
+    pub fn par_try_map_inplace<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: Fn(&mut A) -> Result<(), E> + Sync + Send,
+        E: Send,
+    {
+        self.view_mut().into_par_iter().try_for_each(f)
+    }
+
+    /// Parallel version of `nan_to_num_inplace`.
+    ///
+    /// Replace NaN by `nan`, positive infinity by `posinf` and negative
+    /// infinity by `neginf`, in place.
+    pub fn par_nan_to_num_inplace(&mut self, nan: A, posinf: A, neginf: A)
+    where
+        A: Float,
+    {
+        self.par_mapv_inplace(move |x| nan_to_num(x, nan, posinf, neginf))
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: Sync,
+{
+    /// Parallel version of `map_indexed`.
+    ///
+    /// Call `f` with the index and a reference to each element, and create a
+    /// new array with the new values.
+    ///
+    /// Elements are visited in arbitrary order. Like `Zip::indexed`, this is only
+    /// available for arrays with a fixed number of dimensions.
+    pub fn par_map_indexed<B, F>(&self, f: F) -> Array<B, D>
+    where
+        F: Fn(D::Pattern, &A) -> B + Sync + Send,
+        B: Send,
+        D: Copy,
+        D::Pattern: Send,
+    {
+        Zip::indexed(self).par_map_collect(f)
+    }
+
+    /// Parallel version of `norm_l2`.
+    ///
+    /// Return the Euclidean (L2) norm of the array, the square root of the sum
+    /// of the squares of the elements. The sum is computed with a parallel
+    /// reduction, so the result may differ from `norm_l2` by rounding.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3., 0.], [0., -4.]];
+    /// assert_eq!(a.par_norm_l2(), 5.);
+    /// ```
+    pub fn par_norm_l2(&self) -> A
+    where
+        A: Float + Send,
+    {
+        self.view()
+            .into_par_iter()
+            .map(|&x| x * x)
+            .reduce(A::zero, |a, b| a + b)
+            .sqrt()
+    }
+
+    /// Parallel map that collects the results into a vector in *logical order*.
+    ///
+    /// Call `f` with a reference to each element, and return a vector of the
+    /// results where the result for the element at logical position `i` (in the
+    /// order of [`.iter()`](ArrayBase::iter)) is at index `i`, regardless of the
+    /// memory layout of the array.
+    ///
+    /// Elements are visited in arbitrary order, but each result is written
+    /// directly into its slot of the output, so no reordering is needed.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_shape_fn((3, 4), |(i, j)| 10 * i + j);
+    /// let v = a.t().par_map_collect_vec(|&x| x * 2);
+    /// assert_eq!(v, a.t().iter().map(|&x| x * 2).collect::<Vec<_>>());
+    /// ```
+    pub fn par_map_collect_vec<B, F>(&self, f: F) -> Vec<B>
+    where
+        F: Fn(&A) -> B + Sync + Send,
+        B: Send,
+    {
+        // the output is in standard layout, so its memory order is the logical order
+        let mut output = Array::uninit(self.raw_dim());
+        Zip::from(self)
+            .and(&mut output)
+            .par_for_each(|elt, out| out.assign_elem(f(elt)));
+        unsafe {
+            // all elements of the output have been written
+            output.assume_init().into_raw_vec()
+        }
+    }
 }
 
+/// # Parallel methods for constructing arrays
+///
+/// These methods require crate feature `rayon`.
+impl<A, D> Array<A, D>
+where
+    D: Dimension,
+    A: Send,
+{
+    /// Create an array with the given shape from the items of an indexed
+    /// parallel iterator.
+    ///
+    /// The items are placed in logical order: the item at position `i` of the
+    /// iterator ends up at the `i`-th position of the array in row-major order,
+    /// whatever the memory layout requested by `shape`. This is the parallel
+    /// analogue of [`from_shape_fn`](ArrayBase::from_shape_fn), for iterators
+    /// whose items are expensive to compute.
+    ///
+    /// **Panics** if the length of the iterator is not equal to the number of
+    /// elements in `shape`, or if the product of non-zero axis lengths overflows
+    /// `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array, ShapeBuilder};
+    /// use ndarray::parallel::prelude::*;
+    ///
+    /// let a = Array::par_from_shape_iter((2, 3), (0..6).into_par_iter().map(|x| x * x));
+    /// assert_eq!(a, array![[0, 1, 4], [9, 16, 25]]);
+    ///
+    /// let f = Array::par_from_shape_iter((2, 3).f(), (0..6).into_par_iter());
+    /// assert_eq!(f, array![[0, 1, 2], [3, 4, 5]]);
+    /// assert!(f.t().is_standard_layout());
+    /// ```
+    pub fn par_from_shape_iter<Sh, I>(shape: Sh, iter: I) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        I: IntoParallelIterator<Item = A>,
+        I::Iter: IndexedParallelIterator,
+    {
+        let shape = shape.into_shape();
+        let len = match size_of_shape_checked(&shape.dim) {
+            Ok(len) => len,
+            Err(_) => panic!("ndarray: par_from_shape_iter: shape too large, product of non-zero axis lengths overflows isize"),
+        };
+        let iter = iter.into_par_iter();
+        assert_eq!(iter.len(), len,
+                   "ndarray: par_from_shape_iter: iterator length does not match the shape {:?}",
+                   shape.dim);
+        if shape.is_c() {
+            let mut v = Vec::with_capacity(len);
+            iter.collect_into_vec(&mut v);
+            return unsafe { Self::from_shape_vec_unchecked(shape, v) };
+        }
+        // write each item directly at the memory offset of its logical index
+        let mut output = Array::uninit(shape);
+        let dim = output.raw_dim();
+        let strides = output.strides.clone();
+        let ptr = SyncPtr(output.as_mut_ptr());
+        iter.enumerate().for_each(|(mut i, elt)| {
+            let mut offset = 0;
+            for (&d, &s) in dim.slice().iter().zip(strides.slice()).rev() {
+                offset += (i % d) * s;
+                i /= d;
+            }
+            unsafe {
+                // each offset is written exactly once, and all are in bounds
+                ptr.get().add(offset).write(MaybeUninit::new(elt));
+            }
+        });
+        unsafe {
+            // all elements of the output have been written
+            output.assume_init()
+        }
+    }
+
+    /// Parallel version of [`from_shape_fn`](ArrayBase::from_shape_fn).
+    ///
+    /// Create an array with values created by the function `f`, which is
+    /// called with the index of the element to create. The elements are
+    /// computed in parallel and in arbitrary order, each written directly into
+    /// its own place in the array. Like `Zip::indexed`, this is only available
+    /// for arrays with a fixed number of dimensions.
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array};
+    ///
+    /// let ij_table = Array::par_from_shape_fn((3, 3), |(i, j)| (1 + i) * (1 + j));
+    ///
+    /// assert_eq!(
+    ///     ij_table,
+    ///     arr2(&[[1, 2, 3],
+    ///            [2, 4, 6],
+    ///            [3, 6, 9]])
+    /// );
+    /// ```
+    pub fn par_from_shape_fn<Sh, F>(shape: Sh, f: F) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        F: Fn(D::Pattern) -> A + Sync + Send,
+        D: Copy,
+        D::Pattern: Send,
+    {
+        let mut output = Array::uninit(shape);
+        Zip::indexed(&mut output).par_for_each(|index, elt| elt.assign_elem(f(index)));
+        unsafe {
+            // all elements of the output have been written
+            output.assume_init()
+        }
+    }
+}
+
+/// A pointer to the elements of an output array, shared by the parallel jobs
+/// that each write to their own elements.
+struct SyncPtr<T>(*mut T);
+
+impl<T> SyncPtr<T> {
+    fn get(&self) -> *mut T { self.0 }
+}
+
+unsafe impl<T: Send> Send for SyncPtr<T> { }
+unsafe impl<T: Send> Sync for SyncPtr<T> { }
+
 // Zip
 
 const COLLECT_MAX_SPLITS: usize = 10;
@@ -71,6 +325,25 @@ macro_rules! zip_impl {
                 self.into_par_iter().for_each(move |($($p,)*)| function($($p),*))
             }
 
+            /// The `par_for_each` method for `Zip`, with a minimum job size.
+            ///
+            /// This is a shorthand for using
+            /// `.into_par_iter().with_min_len(min_len).for_each()` on `Zip`: the
+            /// Zip is not split into jobs of fewer than `min_len` elements, which
+            /// keeps the task overhead from dominating when the closure is cheap.
+            /// `.par_for_each()` uses no minimum.
+            ///
+            /// ***Panics*** if `min_len` is zero.
+            ///
+            /// Requires crate feature `rayon`.
+            pub fn par_for_each_with_min_len<F>(self, min_len: usize, function: F)
+                where F: Fn($($p::Item),*) + Sync + Send
+            {
+                self.into_par_iter()
+                    .with_min_len(min_len)
+                    .for_each(move |($($p,)*)| function($($p),*))
+            }
+
             /// The `par_apply` method for `Zip`.
             ///
             /// This is a shorthand for using `.into_par_iter().for_each()` on
@@ -227,6 +500,59 @@ macro_rules! zip_impl {
                     .reduce(identity, reduce)
             }
 
+            /// Parallel version of `fold_while`.
+            ///
+            /// Like [`par_fold`](Zip::par_fold), this splits the producer in multiple tasks
+            /// which each accumulate a single value, starting from `identity()`, and then
+            /// combines the task results using `reduce`. Within a task, the fold stops at
+            /// the first `FoldWhile::Done`, and the value it carries is the final value of
+            /// that task.
+            ///
+            /// Unlike the sequential `fold_while`, returning `Done` does *not* stop the other
+            /// tasks: they still run to completion (or until they are done too), and all
+            /// task results are included in the reduction. Since the splitting into tasks is
+            /// not deterministic, neither is which elements are visited after a `Done`.
+            ///
+            /// ## Examples
+            ///
+            /// ```rust
+            /// use ndarray::{Array, FoldWhile, Zip};
+            ///
+            /// let a = Array::<f64, _>::ones((128, 1024));
+            ///
+            /// // Check whether the squared norm exceeds a threshold; each task stops
+            /// // summing once its own partial sum is above the threshold.
+            /// let threshold = 1000.;
+            /// let partial_norm = Zip::from(&a).par_fold_while(
+            ///     || 0.,
+            ///     |acc, &x| {
+            ///         let acc = acc + x * x;
+            ///         if acc > threshold { FoldWhile::Done(acc) } else { FoldWhile::Continue(acc) }
+            ///     },
+            ///     |acc, other| acc + other,
+            /// );
+            ///
+            /// assert!(partial_norm > threshold);
+            /// ```
+            pub fn par_fold_while<ID, F, R, T>(self, identity: ID, fold: F, reduce: R) -> T
+            where
+                ID: Fn() -> T + Send + Sync + Clone,
+                F: Fn(T, $($p::Item),*) -> FoldWhile<T> + Send + Sync,
+                R: Fn(T, T) -> T + Send + Sync,
+                T: Send
+            {
+                let task_identity = identity.clone();
+                self.into_par_iter()
+                    .fold(move || FoldWhile::Continue(task_identity()), move |accumulator, ($($p,)*)| {
+                        match accumulator {
+                            FoldWhile::Continue(acc) => fold(acc, $($p),*),
+                            done => done,
+                        }
+                    })
+                    .map(FoldWhile::into_inner)
+                    .reduce(identity, reduce)
+            }
+
             );
         }
         )+
@@ -56,6 +56,9 @@ pub use crate::NewAxis;
 #[doc(no_inline)]
 pub use crate::AsArray;
 
+#[doc(no_inline)]
+pub use crate::CollectArray;
+
 #[doc(no_inline)]
 #[cfg(feature = "std")]
 pub use crate::NdFloat;
@@ -176,6 +176,66 @@ where
     Ok(res)
 }
 
+/// Stack owned arrays along the new axis, consuming them.
+///
+/// The elements are moved into the result, so unlike [`stack()`], this does not
+/// require `A: Clone`.
+///
+/// ***Errors*** if the arrays have mismatching shapes.
+/// ***Errors*** if `arrays` is empty, if `axis` is out of bounds,
+/// if the result is larger than is possible to represent.
+///
+/// ```
+/// use ndarray::{arr2, arr3, stack_owned, Axis};
+///
+/// let a = arr2(&[[1, 2],
+///                [3, 4]]);
+/// let b = arr2(&[[5, 6],
+///                [7, 8]]);
+/// assert_eq!(
+///     stack_owned(Axis(0), vec![a, b]),
+///     Ok(arr3(&[[[1, 2],
+///                [3, 4]],
+///               [[5, 6],
+///                [7, 8]]]))
+/// );
+/// ```
+pub fn stack_owned<A, D>(
+    axis: Axis,
+    arrays: Vec<Array<A, D>>,
+) -> Result<Array<A, D::Larger>, ShapeError>
+where
+    D: Dimension,
+    D::Larger: RemoveAxis,
+{
+    if arrays.is_empty() {
+        return Err(from_kind(ErrorKind::Unsupported));
+    }
+    let common_dim = arrays[0].raw_dim();
+    // Avoid panic on `insert_axis` call, return an Err instead of it.
+    if axis.index() > common_dim.ndim() {
+        return Err(from_kind(ErrorKind::OutOfBounds));
+    }
+    let mut res_dim = common_dim.insert_axis(axis);
+
+    if arrays.iter().any(|a| a.raw_dim() != common_dim) {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+
+    res_dim.set_axis(axis, arrays.len());
+    dimension::size_of_shape_checked(&res_dim)?;
+
+    let mut res = Array::uninit(res_dim);
+    for (array, subview) in arrays.into_iter().zip(res.axis_iter_mut(axis)) {
+        // the subview has the same dimensionality as D, only the static type differs
+        array.move_into_uninit(subview.into_dimensionality::<D>().unwrap());
+    }
+    unsafe {
+        // Safety: every subview along `axis` has been written by `move_into_uninit`
+        Ok(res.assume_init())
+    }
+}
+
 /// Stack arrays along the new axis.
 ///
 /// Uses the [`stack()`] function, calling `ArrayView::from(&a)` on each
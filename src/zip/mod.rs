@@ -669,6 +669,34 @@ macro_rules! map_impl {
                 }).is_done()
             }
 
+            /// Tests if any element of the iterator matches a predicate.
+            ///
+            /// Returns `true` if `predicate` evaluates to `true` for any elements,
+            /// stopping at the first element that matches.
+            /// Returns `false` if the input arrays are empty.
+            ///
+            /// Example:
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            /// let a = array![1, 2, 3];
+            /// let b = array![1, 4, 9];
+            /// assert!(Zip::from(&a).and(&b).any(|&a, &b| a == b));
+            /// assert!(!Zip::from(&a).and(&b).any(|&a, &b| a > b));
+            /// ```
+            pub fn any<F>(mut self, mut predicate: F) -> bool
+                where F: FnMut($($p::Item),*) -> bool
+            {
+                self.for_each_core((), move |_, args| {
+                    let ($($p,)*) = args;
+                    if predicate($($p),*) {
+                        FoldWhile::Done(())
+                    } else {
+                        FoldWhile::Continue(())
+                    }
+                }).is_done()
+            }
+
             expand_if!(@bool [$notlast]
 
             /// Include the producer `p` in the Zip.
@@ -716,6 +744,35 @@ macro_rules! map_impl {
                 self.build_and(part)
             }
 
+            /// Include a mutable view of `array` in the Zip, so that its elements
+            /// are visited as `&mut A` and can be modified in place.
+            ///
+            /// This is the same as `.and(&mut array)`, spelled out: the array is
+            /// borrowed exclusively for as long as the Zip lives, so it can't be
+            /// used by another part of the Zip at the same time. It works the
+            /// same with `.for_each()` and, with crate feature `rayon`,
+            /// `.par_for_each()`.
+            ///
+            /// ***Panics*** if `array`’s shape doesn’t match the Zip’s exactly.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![[1., 2.], [3., 4.]];
+            /// let b = array![[10., 20.], [30., 40.]];
+            /// let mut c = a.clone();
+            ///
+            /// // c += a * b, reading a and b and writing c
+            /// Zip::from(&a).and(&b).and_mut(&mut c).for_each(|&a, &b, c| *c += a * b);
+            /// assert_eq!(c, array![[11., 42.], [93., 164.]]);
+            /// ```
+            pub fn and_mut<'a, A, S>(self, array: &'a mut ArrayBase<S, D>)
+                -> Zip<($($p,)* ArrayViewMut<'a, A, D>, ), D>
+                where S: DataMut<Elem = A>,
+            {
+                self.and(array.view_mut())
+            }
+
             fn build_and<P>(self, part: P) -> Zip<($($p,)* P, ), D>
                 where P: NdProducer<Dim=D>,
             {
@@ -801,10 +858,35 @@ macro_rules! map_impl {
 
             /// Split the `Zip` evenly in two.
             ///
-            /// It will be split in the way that best preserves element locality.
+            /// It will be split in the way that best preserves element locality:
+            /// the axis with the largest stride (in the layout the producers
+            /// agree on) that has a length of more than one is cut in half, so
+            /// the lengths of that axis in the two parts differ by at most one.
+            /// Together the two parts visit every element of the `Zip` exactly
+            /// once, and each part can be split again for divide-and-conquer
+            /// processing. This is the primitive used by the Rayon integration.
+            ///
+            /// **Panics** if the `Zip` has fewer than two elements, since it
+            /// can't be split; check [`.size()`](Zip::size) first.
+            ///
+            /// ```
+            /// use ndarray::{Array, Zip};
+            ///
+            /// fn sum(zip: Zip<(ndarray::ArrayView2<'_, i32>,), ndarray::Ix2>) -> i32 {
+            ///     if zip.size() <= 4 {
+            ///         zip.fold(0, |acc, &x| acc + x)
+            ///     } else {
+            ///         let (a, b) = zip.split();
+            ///         sum(a) + sum(b)
+            ///     }
+            /// }
+            ///
+            /// let a = Array::from_shape_fn((5, 7), |(i, j)| (10 * i + j) as i32);
+            /// assert_eq!(sum(Zip::from(a.view())), a.sum());
+            /// ```
             pub fn split(self) -> (Self, Self) {
-                debug_assert_ne!(self.size(), 0, "Attempt to split empty zip");
-                debug_assert_ne!(self.size(), 1, "Attempt to split zip with 1 elem");
+                assert!(self.size() > 1,
+                        "ndarray: Zip::split: can't split a zip with {} elements", self.size());
                 SplitPreference::split(self)
             }
         }
@@ -918,7 +1000,7 @@ map_impl! {
     [false P1 P2 P3 P4 P5 P6],
 }
 
-/// Value controlling the execution of `.fold_while` on `Zip`.
+/// Value controlling the execution of `.fold_while` on `Zip` and on arrays.
 #[derive(Debug, Copy, Clone)]
 pub enum FoldWhile<T> {
     /// Continue folding with this value
@@ -401,3 +401,65 @@ fn zero_dimensional_ok() {
     a.push(Axis(0), one).unwrap();
     assert_eq!(a, array![2, 1, 1]);
 }
+
+#[test]
+fn resize() {
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (10 * i + j).to_string());
+    let ptr = a.as_ptr();
+    a.resize(2, String::new());
+    assert_eq!(a, array![["0", "1", "2"], ["10", "11", "12"]].map(|s| s.to_string()));
+    assert_eq!(a.as_ptr(), ptr);
+    a.resize(3, "x".to_string());
+    assert_eq!(a.row(2), array!["x", "x", "x"].map(|s| s.to_string()));
+    a.resize(0, String::new());
+    assert_eq!(a.shape(), &[0, 3]);
+    a.resize(1, "y".to_string());
+    assert_eq!(a, array![["y", "y", "y"]].map(|s| s.to_string()));
+}
+
+#[test]
+fn resized_non_standard_layout() {
+    let a = Array::from_shape_fn((3, 4).f(), |(i, j)| 10 * i + j);
+    let b = a.clone().resized(2, 0);
+    assert_eq!(b, a.slice(s![..2, ..]));
+    let b = a.clone().resized(5, 7);
+    assert_eq!(b.slice(s![..3, ..]), a);
+    assert_eq!(b.slice(s![3.., ..]), Array::from_elem((2, 4), 7));
+
+    let c = a.slice_move(s![..;-1, 1..]);
+    let d = c.clone().resized(4, 1);
+    assert_eq!(d.slice(s![..3, ..]), c);
+    assert_eq!(d.row(3), array![1, 1, 1]);
+    assert_eq!(c.clone().resized(1, 0), c.slice(s![..1, ..]));
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (10 * i + j).to_string());
+    a.append(Axis(0), a.clone().view()).unwrap();
+    let b = a.clone();
+    a.shrink_to_fit();
+    assert_eq!(a, b);
+    assert_eq!(a.into_raw_vec().capacity(), 24);
+
+    // reversed axes still cover all of the storage
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| 10 * i + j).slice_move(s![..;-1, ..]);
+    a.push(Axis(1), array![1, 2, 3, 4].view()).unwrap();
+    let b = a.clone();
+    a.shrink_to_fit();
+    assert_eq!(a, b);
+
+    // sliced arrays are left as they are
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| 10 * i + j).slice_move(s![1.., ..]);
+    let ptr = a.as_ptr();
+    a.shrink_to_fit();
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a.into_raw_vec().len(), 12);
+}
+
+#[test]
+#[should_panic]
+fn resize_zero_dim() {
+    let mut a = arr0(1).into_dyn();
+    a.resize(1, 0);
+}
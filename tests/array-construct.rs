@@ -147,6 +147,35 @@ fn test_from_fn_f3() {
     }
 }
 
+#[test]
+fn test_from_fn_mut_order() {
+    for &f_order in &[false, true] {
+        let mut counter = 0;
+        let a = Array::from_shape_fn_mut((3, 2, 4).set_f(f_order), |_| {
+            counter += 1;
+            counter
+        });
+        assert_eq!(a.is_standard_layout(), !f_order);
+        assert_eq!(a, Array::from_iter(1..=24).into_shape((3, 2, 4)).unwrap());
+    }
+}
+
+#[test]
+fn test_from_fn_mut_index() {
+    let mut seen = Vec::new();
+    let a = Array::from_shape_fn_mut((2, 3).f(), |i| {
+        seen.push(i);
+        i
+    });
+    for (i, elt) in a.indexed_iter() {
+        assert_eq!(i, *elt);
+    }
+    assert_eq!(seen, a.indexed_iter().map(|(i, _)| i).collect::<Vec<_>>());
+
+    let a = Array::from_shape_fn_mut(vec![2, 0, 3].f(), |_| 0);
+    assert_eq!(a.shape(), &[2, 0, 3]);
+}
+
 #[test]
 fn deny_wraparound_from_vec() {
     let five = vec![0; 5];
@@ -165,6 +194,38 @@ fn test_ones() {
     assert_eq!(a, b);
 }
 
+#[test]
+fn test_from_elem() {
+    let a = Array::from_elem((2, 3), String::from("x"));
+    assert_eq!(a.shape(), &[2, 3]);
+    assert!(a.iter().all(|s| s == "x"));
+
+    // zero-sized shapes do not allocate
+    let e = Array::from_elem((3, 0, 2), 1.);
+    assert_eq!(e.len(), 0);
+    assert_eq!(e.into_raw_vec().capacity(), 0);
+}
+
+#[test]
+fn test_collect_array() {
+    let a = (0..24).collect_array((2, 3, 4)).unwrap();
+    assert_eq!(a, Array::from_shape_fn((2, 3, 4), |(i, j, k)| 12 * i + 4 * j + k));
+
+    let a = (0..6).collect_array((2, 3).f()).unwrap();
+    assert_eq!(a, arr2(&[[0, 2, 4], [1, 3, 5]]));
+
+    let a = vec![1., 2.].into_iter().collect_array(IxDyn(&[2, 1])).unwrap();
+    assert_eq!(a.shape(), &[2, 1]);
+
+    // empty iterator and zero-size shape
+    let e = std::iter::empty::<f32>().collect_array((0, 3)).unwrap();
+    assert_eq!(e.shape(), &[0, 3]);
+
+    for &len in &[0, 5, 7] {
+        assert!((0..len).collect_array((2, 3)).is_err());
+    }
+}
+
 #[test]
 fn test_from_shape_empty_with_neg_stride() {
     // Issue #998, negative strides for an axis where it doesn't matter.
@@ -217,6 +278,38 @@ fn deny_wraparound_reshape() {
     let _five_large = five.into_shape((3, 7, 29, 36760123, 823996703)).unwrap();
 }
 
+#[test]
+fn from_elem_aligned() {
+    for &align in &[16, 32, 64, 128] {
+        let a = Array::from_elem_aligned((3, 5), 2.0f32, align);
+        assert_eq!(a.as_ptr() as usize % align, 0);
+        assert_eq!(a, Array::from_elem((3, 5), 2.));
+        assert!(a.is_standard_layout());
+
+        let b = Array::from_elem_aligned((3, 5).f(), 1u8, align);
+        assert_eq!(b.as_ptr() as usize % align, 0);
+        assert_eq!(b.strides(), &[1, 3]);
+
+        let c = ArcArray::from_elem_aligned([4, 0, 2], [0f64; 3], align);
+        assert_eq!(c.as_ptr() as usize % align, 0);
+        assert_eq!(c.len(), 0);
+    }
+    let z = Array::from_elem_aligned(4, (), 64);
+    assert_eq!(z.len(), 4);
+}
+
+#[should_panic]
+#[test]
+fn from_elem_aligned_not_power_of_two() {
+    let _a = Array::from_elem_aligned(4, 0f32, 48);
+}
+
+#[should_panic]
+#[test]
+fn from_elem_aligned_unalignable_elements() {
+    let _a = Array::from_elem_aligned(4, [0f32; 2], 64);
+}
+
 #[should_panic]
 #[test]
 fn deny_wraparound_default() {
@@ -13,6 +13,7 @@ use itertools::{zip, Itertools};
 use ndarray::prelude::*;
 use ndarray::{arr3, rcarr2};
 use ndarray::indices;
+use ndarray::Order;
 use ndarray::{Slice, SliceInfo, SliceInfoElem};
 use num_complex::Complex;
 use std::convert::TryFrom;
@@ -80,6 +81,15 @@ fn test_mat_mul() {
     assert_eq!(c.dot(&a), a);
 }
 
+#[test]
+fn test_eye_identity() {
+    let m = arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(Array2::eye(3).dot(&m), m);
+    assert_eq!(m.dot(&Array2::eye(3)), m);
+    assert_eq!(Array2::<i32>::identity(3), Array2::eye(3));
+    assert_eq!(Array2::<f64>::identity(0).shape(), &[0, 0]);
+}
+
 #[deny(unsafe_code)]
 #[test]
 fn test_slice() {
@@ -654,6 +664,27 @@ fn test_cow_shrink() {
     assert_eq!(before[1], 5);
 }
 
+#[test]
+fn test_ensure_unique_copied() {
+    use ndarray::{CowRepr, DataMut, OwnedArcRepr, OwnedRepr};
+
+    let mut a = ArcArray::from_elem((2, 3), 1);
+    assert!(!OwnedArcRepr::ensure_unique_copied(&mut a));
+    let b = a.clone();
+    assert!(OwnedArcRepr::ensure_unique_copied(&mut a));
+    assert!(!OwnedArcRepr::ensure_unique_copied(&mut a));
+    a[[0, 0]] = 2;
+    assert_eq!(b[[0, 0]], 1);
+
+    let mut owned = Array::from_elem(3, 0);
+    assert!(!OwnedRepr::ensure_unique_copied(&mut owned));
+
+    let mut cow = CowArray::from(owned.view());
+    assert!(CowRepr::ensure_unique_copied(&mut cow));
+    assert!(cow.is_owned());
+    assert!(!CowRepr::ensure_unique_copied(&mut cow));
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn test_sub() {
@@ -724,6 +755,30 @@ fn test_select_1d() {
     assert_eq!(r3, arr1(&[]));
 }
 
+#[test]
+fn test_filter_axis() {
+    let x = Array::from_shape_fn((3, 4, 2), |(i, j, k)| 100 * i + 10 * j + k);
+    let r = x.filter_axis(Axis(1), &[true, false, false, true]);
+    assert_eq!(r, x.select(Axis(1), &[0, 3]));
+
+    let xt = x.t();
+    let r = xt.filter_axis(Axis(2), &[false, true, true]);
+    assert_eq!(r, xt.slice(s![.., .., 1..]));
+
+    let r = x.filter_axis(Axis(0), &[false; 3]);
+    assert_eq!(r.shape(), &[0, 4, 2]);
+
+    let v = arr1(&[1, 2, 3]);
+    assert_eq!(v.filter_axis(Axis(0), &[true, false, true]), arr1(&[1, 3]));
+}
+
+#[test]
+#[should_panic]
+fn test_filter_axis_mask_len() {
+    let x = arr2(&[[0, 1], [2, 3]]);
+    x.filter_axis(Axis(0), &[true]);
+}
+
 #[test]
 fn diag() {
     let d = arr2(&[[1., 2., 3.0f32]]).into_diag();
@@ -737,6 +792,24 @@ fn diag() {
     assert_eq!(d.dim(), 1);
 }
 
+#[test]
+fn diag_3d() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| 100 * i + 10 * j + k);
+    let d = a.diag();
+    assert_eq!(d, arr1(&[0, 111, 222]));
+    for (i, &x) in d.iter().enumerate() {
+        assert_eq!(x, a[[i, i, i]]);
+    }
+
+    let v = a.slice(s![..;-1, 1.., ..;2]);
+    assert_eq!(v.diag(), arr1(&[210, 122, 34]));
+
+    let mut a = a;
+    a.diag_mut().fill(0);
+    assert_eq!(a[[2, 2, 2]], 0);
+    assert_eq!(a[[2, 2, 1]], 221);
+}
+
 /// Check that the merged shape is correct.
 ///
 /// Note that this does not check the strides in the "merged" case!
@@ -991,6 +1064,24 @@ fn map1() {
     assert_eq!(a[(0, 0)], *c[(0, 0)]);
 }
 
+#[test]
+fn convert() {
+    let a = arr2(&[[1u8, 2], [3, 255]]);
+    let b: Array2<u32> = a.t().convert();
+    assert_eq!(b, arr2(&[[1, 3], [2, 255]]));
+    assert_eq!(a.convert::<f32>(), arr2(&[[1., 2.], [3., 255.]]));
+    assert_eq!(arr1(&[true, false]).convert::<i32>(), arr1(&[1, 0]));
+}
+
+#[test]
+fn convert_lossy() {
+    let a = arr1(&[-1.7f64, 2.5, 1e10, f64::NAN]);
+    assert_eq!(a.convert_lossy::<i32>(), arr1(&[-1, 2, i32::MAX, 0]));
+    assert_eq!(a.convert_lossy::<f32>()[1], 2.5f32);
+    assert_eq!(arr1(&[257i32, -1]).convert_lossy::<u8>(), arr1(&[1, 255]));
+    assert_eq!(arr1(&[3u8]).convert_lossy::<u8>(), arr1(&[3]));
+}
+
 #[test]
 fn mapv_into_any_same_type() {
     let a: Array<f64, _> = array![[1., 2., 3.], [4., 5., 6.]];
@@ -1084,6 +1175,31 @@ fn to_slice_memory_order_discontiguous() {
         .is_none());
 }
 
+#[test]
+fn into_raw_vec_storage() {
+    // unique storage is returned without copying, in storage order
+    let a = array![[1, 2, 3], [4, 5, 6]].reversed_axes();
+    let ptr = a.as_ptr();
+    let v = a.into_raw_vec();
+    assert_eq!(v, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(v.as_ptr(), ptr);
+
+    // including uniquely held shared storage, and the elements sliced away
+    let a = ArcArray::from_vec(vec![1, 2, 3, 4]).slice_move(s![1..3]);
+    let ptr = a.as_ptr();
+    let v = a.into_raw_vec();
+    assert_eq!(v, vec![1, 2, 3, 4]);
+    assert_eq!(v.as_ptr(), ptr.wrapping_sub(1));
+
+    // shared storage that is still held elsewhere is copied
+    let a = ArcArray::from_vec(vec![1, 2, 3]);
+    let b = a.clone();
+    let v = a.into_raw_vec();
+    assert_eq!(v, vec![1, 2, 3]);
+    assert_ne!(v.as_ptr(), b.as_ptr());
+    assert_eq!(b, arr1(&[1, 2, 3]));
+}
+
 #[test]
 fn array0_into_scalar() {
     // With this kind of setup, the `Array`'s pointer is not the same as the
@@ -1441,6 +1557,27 @@ fn transpose_view() {
     assert_eq!(at, arr2(&[[1, 4], [2, 5], [3, 6]]));
 }
 
+#[test]
+fn reversed_axes_roundtrip() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let v = a.slice(s![.., ..;-1, 1..;2]);
+    let ptr = v.as_ptr();
+    let vt = v.reversed_axes();
+    assert_eq!(vt.shape(), &[2, 3, 2]);
+    assert_eq!(vt.strides(), &[2, -4, 12]);
+    assert_eq!(vt[[1, 2, 0]], v[[0, 2, 1]]);
+    assert_eq!(vt.as_ptr(), ptr);
+
+    let vtt = vt.reversed_axes();
+    assert_eq!(vtt.shape(), v.shape());
+    assert_eq!(vtt.strides(), v.strides());
+    assert_eq!(vtt, v);
+
+    let b = a.clone().into_dyn().reversed_axes().reversed_axes();
+    assert_eq!(b.shape(), a.shape());
+    assert_eq!(b.strides(), a.strides());
+}
+
 #[test]
 fn transpose_view_mut() {
     let mut a = arr2(&[[1, 2], [3, 4]]);
@@ -1674,6 +1811,39 @@ fn arithmetic_broadcast() {
     assert_eq!(&a + &b, arr3(&[[[3, 7], [19, 23]], [[3, 7], [19, 23]]]));
 }
 
+#[test]
+fn zip_mut_with() {
+    // same shape, different memory layouts
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| 10 * i + j);
+    let b = Array::from_shape_fn((3, 4).f(), |(i, j)| i + j);
+    let mut answer = a.clone();
+    for ((i, j), elt) in answer.indexed_iter_mut() {
+        *elt -= b[[i, j]];
+    }
+    a.zip_mut_with(&b, |x, &y| *x -= y);
+    assert_eq!(a, answer);
+
+    // rhs with negative strides
+    let mut a = Array::<i32, _>::zeros((3, 4));
+    let b = Array::from_shape_fn((3, 4), |(i, j)| (4 * i + j) as i32);
+    a.zip_mut_with(&b.slice(s![..;-1, ..;-1]), |x, &y| *x = y);
+    assert_eq!(a, b.slice(s![..;-1, ..;-1]));
+
+    // rhs is broadcast to the shape of self
+    let mut a = Array::<i32, _>::zeros((2, 3));
+    a.zip_mut_with(&arr1(&[1, 2, 3]), |x, &y| *x += y);
+    assert_eq!(a, arr2(&[[1, 2, 3], [1, 2, 3]]));
+    a.zip_mut_with(&arr0(10), |x, &y| *x *= y);
+    assert_eq!(a, arr2(&[[10, 20, 30], [10, 20, 30]]));
+}
+
+#[test]
+#[should_panic]
+fn zip_mut_with_incompatible_shape() {
+    let mut a = Array::<i32, _>::zeros((2, 3));
+    a.zip_mut_with(&Array::<i32, _>::zeros((3, 2)), |x, &y| *x += y);
+}
+
 #[test]
 fn char_array() {
     // test compilation & basics of non-numerical array
@@ -1770,6 +1940,50 @@ fn deny_split_at_index_out_of_bounds() {
     a.view().split_at(Axis(1), 3);
 }
 
+#[test]
+fn split_first_last_along() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| 100 * i + 10 * j + k);
+
+    let (first, rest) = a.view().split_first_along(Axis(1)).unwrap();
+    assert_eq!(first, a.index_axis(Axis(1), 0));
+    assert_eq!(rest, a.slice(s![.., 1.., ..]));
+
+    let (last, rest) = a.view().split_last_along(Axis(0)).unwrap();
+    assert_eq!(last, a.index_axis(Axis(0), 2));
+    assert_eq!(rest, a.slice(s![..2, .., ..]));
+
+    // peel off every row in turn
+    let mut rest = a.view();
+    let mut count = 0;
+    while let Some((first, tail)) = rest.split_first_along(Axis(0)) {
+        assert_eq!(first, a.index_axis(Axis(0), count));
+        rest = tail;
+        count += 1;
+    }
+    assert_eq!(count, 3);
+    assert_eq!(rest.shape(), &[0, 4, 2]);
+
+    let mut b = a.clone();
+    {
+        let (mut last, mut rest) = b.view_mut().split_last_along(Axis(0)).unwrap();
+        last.fill(0);
+        rest.fill(1);
+    }
+    assert_eq!(b.slice(s![..2, .., ..]), Array::ones((2, 4, 2)));
+    assert_eq!(b.slice(s![2, .., ..]), Array::zeros((4, 2)));
+
+    let e = Array2::<i32>::zeros((2, 0));
+    assert!(e.view().split_first_along(Axis(1)).is_none());
+    assert!(e.view().split_last_along(Axis(1)).is_none());
+}
+
+#[test]
+#[should_panic]
+fn deny_split_first_along_axis_out_of_bounds() {
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    a.view().split_first_along(Axis(2));
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn test_range() {
@@ -1862,6 +2076,92 @@ fn discontiguous_owned_to_owned() {
     assert_eq!(c, co);
 }
 
+#[test]
+fn to_owned_order() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let v = a.slice(s![..;-1, .., 1..;2]);
+
+    for &arr in &[a.view(), a.t(), v] {
+        let c = arr.to_owned_order(Order::RowMajor);
+        assert_eq!(c, arr);
+        assert!(c.is_standard_layout());
+
+        let f = arr.to_owned_order(Order::ColumnMajor);
+        assert_eq!(f, arr);
+        assert!(f.t().is_standard_layout());
+        assert_eq!(f.as_slice_memory_order().unwrap().to_vec(),
+                   arr.t().iter().cloned().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn hash_layout_independent() {
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    /// Records the sequence of writes, which is what a hasher sees
+    #[derive(Default)]
+    struct RecordingHasher(Vec<Vec<u8>>);
+
+    impl Hasher for RecordingHasher {
+        fn finish(&self) -> u64 { 0 }
+        fn write(&mut self, bytes: &[u8]) { self.0.push(bytes.to_vec()) }
+    }
+
+    fn writes<T: Hash>(value: &T) -> Vec<Vec<u8>> {
+        let mut hasher = RecordingHasher::default();
+        value.hash(&mut hasher);
+        hasher.0
+    }
+
+    let c = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let f = c.t().to_owned().reversed_axes();
+    let sliced = arr2(&[[1, 0, 2, 0, 3], [4, 0, 5, 0, 6]]);
+    let sliced = sliced.slice(s![.., ..;2]);
+    assert_eq!(writes(&c), writes(&f));
+    assert_eq!(writes(&c), writes(&sliced));
+    assert_eq!(writes(&c), writes(&c.view()));
+
+    let mut set = HashSet::new();
+    set.insert(c.clone());
+    assert!(set.contains(&f));
+    assert!(set.contains(&sliced.to_owned()));
+    assert!(!set.insert(f));
+    assert!(set.insert(c.clone().into_shape((3, 2)).unwrap()));
+    assert!(set.insert(arr2(&[[1, 2, 3], [4, 5, 7]])));
+    assert_eq!(set.len(), 3);
+
+    let mut views = HashSet::new();
+    views.insert(c.view());
+    assert!(views.contains(&sliced));
+}
+
+#[test]
+fn array_ordering() {
+    use std::cmp::Ordering;
+
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let b = arr2(&[[1, 2], [3, 5]]);
+    assert!(a < b);
+    assert_eq!(a.cmp(&a.t().to_owned().reversed_axes()), Ordering::Equal);
+    assert_eq!(a.view().partial_cmp(&b.view()), Some(Ordering::Less));
+
+    // the shape is compared first
+    let wide = arr2(&[[0, 0, 0], [0, 0, 0]]);
+    let tall = arr2(&[[9, 9], [9, 9], [9, 9]]);
+    assert!(a < wide);
+    assert!(wide < tall);
+    assert!(aview1(&[5]).into_dyn() < aview2(&[[0]]).into_dyn());
+
+    let mut arrays = vec![tall.clone(), b.clone(), wide.clone(), a.clone()];
+    arrays.sort();
+    assert_eq!(arrays, vec![a, b, wide, tall]);
+
+    let x = arr1(&[1., f64::NAN]);
+    assert_eq!(x.partial_cmp(&x), None);
+    assert_eq!(x.partial_cmp(&arr1(&[0., f64::NAN])), Some(Ordering::Greater));
+}
+
 #[test]
 fn map_memory_order() {
     let a = arr3(&[[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [0, -1, -2]]]);
@@ -1872,6 +2172,40 @@ fn map_memory_order() {
     assert_eq!(amap.strides(), v.strides());
 }
 
+#[test]
+fn map_ordered() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let views = [a.view(), a.t(), a.slice(s![..;-1, .., ..;2]), a.slice(s![.., 1, ..]).insert_axis(Axis(0))];
+    for v in &views {
+        let mut visited = Vec::new();
+        let b = v.map_ordered(|&x| {
+            visited.push(x);
+            x + 1
+        });
+        assert_eq!(visited, v.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(b, v.map(|&x| x + 1));
+        assert!(b.is_standard_layout());
+    }
+}
+
+#[test]
+fn map_indexed() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let b = a.map_indexed(|(i, j, k), &x| {
+        assert_eq!(x, a[[i, j, k]]);
+        x * i
+    });
+    assert_eq!(b, Array::from_shape_fn((2, 3, 4), |(i, j, k)| (100 * i + 10 * j + k) * i));
+
+    let v = a.slice(s![..;-1, .., ..;2]);
+    let b = v.map_indexed(|(i, j, k), &x| (i, j, k, x));
+    assert_eq!(b, Array::from_shape_fn(v.raw_dim(), |(i, j, k)| (i, j, k, v[[i, j, k]])));
+
+    let d = a.view().into_dyn();
+    let b = d.map_indexed(|index, &x| index.slice().iter().sum::<usize>() + x);
+    assert_eq!(b, a.map_indexed(|(i, j, k), &x| i + j + k + x).into_dyn());
+}
+
 #[test]
 fn map_mut_with_unsharing() {
     // Fortran-layout `ArcArray`.
@@ -1928,17 +2262,21 @@ fn test_contiguous() {
     let v = c.slice(s![.., 0..1, ..]);
     assert!(!v.is_standard_layout());
     assert!(!v.as_slice_memory_order().is_some());
+    assert!(!v.is_contiguous());
 
     let v = c.slice(s![1..2, .., ..]);
     assert!(v.is_standard_layout());
     assert!(v.as_slice_memory_order().is_some());
+    assert!(v.is_contiguous());
     let v = v.reversed_axes();
     assert!(!v.is_standard_layout());
     assert!(v.as_slice_memory_order().is_some());
+    assert!(v.is_contiguous());
     let mut v = v.reversed_axes();
     v.swap_axes(1, 2);
     assert!(!v.is_standard_layout());
     assert!(v.as_slice_memory_order().is_some());
+    assert!(v.is_contiguous());
 
     let a = Array::<f32, _>::zeros((20, 1));
     let b = Array::<f32, _>::zeros((20, 1).f());
@@ -2157,6 +2495,86 @@ fn test_map_axis() {
     itertools::assert_equal(result.iter().cloned().sorted(), 1..=3 * 4);
 }
 
+#[test]
+fn test_reduce_axis() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as f64);
+    for ax in 0..3 {
+        assert_eq!(a.reduce_axis(Axis(ax), |lane| lane.sum()), a.sum_axis(Axis(ax)));
+        assert_eq!(a.reduce_axis(Axis(ax), |lane| lane.mean().unwrap()), a.mean_axis(Axis(ax)).unwrap());
+    }
+
+    // the surviving axes have zero length
+    let e = Array3::<f64>::zeros((0, 4, 0));
+    let r = e.reduce_axis(Axis(1), |_| -> f64 { panic!("no lanes to reduce") });
+    assert_eq!(r.shape(), &[0, 0]);
+    // the reduced axis has zero length
+    let r = e.reduce_axis(Axis(0), |lane| lane.len());
+    assert_eq!(r, Array2::<usize>::zeros((4, 0)));
+    let z = Array2::<f64>::zeros((3, 0));
+    assert_eq!(z.reduce_axis(Axis(1), |lane| lane.sum()), z.sum_axis(Axis(1)));
+}
+
+#[test]
+fn test_first_index_axis() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 7 + j * 3 + k) % 6);
+    let r = a.first_index_axis(Axis(2), |&x| x == 0);
+    assert_eq!(r.shape(), &[3, 4]);
+    for ((i, j), &r) in r.indexed_iter() {
+        assert_eq!(r, a.slice(s![i, j, ..]).iter().position(|&x| x == 0));
+    }
+
+    let r = a.first_index_axis(Axis(0), |&x| x > 10);
+    assert_eq!(r, Array::from_elem((4, 5), None));
+
+    // lanes are scanned from index 0 even with negative strides
+    let b = arr2(&[[1, 2, 3], [3, 2, 1]]);
+    let rev = b.slice(s![.., ..;-1]);
+    assert_eq!(rev.first_index_axis(Axis(1), |&x| x >= 2), arr1(&[Some(0), Some(1)]));
+
+    let mut calls = 0;
+    b.first_index_axis(Axis(1), |_| { calls += 1; true });
+    assert_eq!(calls, 2);
+
+    let e = Array2::<i32>::zeros((2, 0));
+    assert_eq!(e.first_index_axis(Axis(1), |_| true), arr1(&[None, None]));
+}
+
+#[test]
+fn test_apply_along_axis() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+
+    // identity along each axis
+    for ax in 0..3 {
+        assert_eq!(a.apply_along_axis(Axis(ax), |lane| lane.to_owned()), a);
+    }
+
+    // reverse and drop the last element of each lane
+    let r = a.apply_along_axis(Axis(1), |lane| lane.slice(s![..-1;-1]).to_owned());
+    assert_eq!(r, a.slice(s![.., ..-1;-1, ..]));
+
+    // lanes can grow
+    let r = a.apply_along_axis(Axis(2), |lane| {
+        Array::from_iter(lane.iter().flat_map(|&x| vec![x, x]))
+    });
+    assert_eq!(r.shape(), &[2, 3, 8]);
+    assert_eq!(r.slice(s![.., .., ..;2]), a);
+    assert_eq!(r.slice(s![.., .., 1..;2]), a);
+
+    // empty results
+    let r = a.apply_along_axis(Axis(0), |_| Array1::<u8>::zeros(0));
+    assert_eq!(r.shape(), &[0, 3, 4]);
+    let e = Array2::<i32>::zeros((0, 3));
+    let r = e.apply_along_axis(Axis(1), |lane| lane.to_owned());
+    assert_eq!(r.shape(), &[0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_apply_along_axis_inconsistent() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i + j);
+    a.apply_along_axis(Axis(1), |lane| lane.slice(s![..lane[0]]).to_owned());
+}
+
 #[test]
 fn test_accumulate_axis_inplace_noop() {
     let mut a = Array2::<u8>::zeros((0, 3));
@@ -2241,6 +2659,130 @@ fn test_array2_from_diag() {
     assert_eq!(x.shape(), [0, 0]);
 }
 
+#[test]
+fn test_array2_from_diag_with_offset() {
+    let diag = arr1(&[1, 2]);
+    let x = Array2::from_diag_with_offset(&diag, 0);
+    assert_eq!(x, Array2::from_diag(&diag));
+
+    let x = Array2::from_diag_with_offset(&diag, 2);
+    let x_exp = arr2(&[[0, 0, 1, 0], [0, 0, 0, 2], [0, 0, 0, 0], [0, 0, 0, 0]]);
+    assert_eq!(x, x_exp);
+
+    let x = Array2::from_diag_with_offset(&diag, -1);
+    let x_exp = arr2(&[[0, 0, 0], [1, 0, 0], [0, 2, 0]]);
+    assert_eq!(x, x_exp);
+
+    // check 0 length array
+    let diag = Array1::<f64>::zeros(0);
+    let x = Array2::from_diag_with_offset(&diag, -2);
+    assert_eq!(x, Array2::zeros((2, 2)));
+}
+
+#[test]
+fn test_array2_one_hot() {
+    let labels = arr1(&[1, 3, 0]);
+    let x = Array2::<i32>::one_hot(&labels, 4);
+    assert_eq!(x, arr2(&[[0, 1, 0, 0], [0, 0, 0, 1], [1, 0, 0, 0]]));
+
+    let x = Array2::<f64>::one_hot(&labels.slice(s![..;-2]), 5);
+    assert_eq!(x, arr2(&[[1., 0., 0., 0., 0.], [0., 1., 0., 0., 0.]]));
+
+    // check 0 length array
+    let x = Array2::<u8>::one_hot(&Array1::zeros(0), 3);
+    assert_eq!(x.shape(), [0, 3]);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_array2_one_hot_out_of_range() {
+    Array2::<f32>::one_hot(&arr1(&[0, 3, 1]), 3);
+}
+
+#[test]
+fn argsort_axis() {
+    let a = arr2(&[[4, 1, 3, 1], [0, 5, 2, 2]]);
+    assert_eq!(a.argsort_axis(Axis(1)), arr2(&[[1, 3, 2, 0], [0, 2, 3, 1]]));
+    assert_eq!(a.argsort_axis(Axis(0)), arr2(&[[1, 0, 1, 0], [0, 1, 0, 1]]));
+
+    // non-contiguous lanes
+    let v = a.t();
+    assert_eq!(v.argsort_axis(Axis(0)), a.argsort_axis(Axis(1)).reversed_axes());
+    let s = a.slice(s![.., ..;-1]);
+    assert_eq!(s.argsort_axis(Axis(1)), arr2(&[[0, 2, 1, 3], [3, 0, 1, 2]]));
+
+    let f = arr1(&[2., f64::NAN, -1., f64::NAN, 0.]);
+    assert_eq!(f.argsort_axis(Axis(0)), arr1(&[2, 4, 0, 1, 3]));
+
+    let d = Array::from_shape_fn(vec![2, 0, 3], |_| 0).into_dyn();
+    assert_eq!(d.argsort_axis(Axis(2)).shape(), &[2, 0, 3]);
+    let by = a.argsort_axis_by(Axis(1), |x, y| y.cmp(x));
+    assert_eq!(by, arr2(&[[0, 2, 1, 3], [1, 2, 3, 0]]));
+}
+
+#[test]
+fn take_along_axis() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let order = a.map(|&x| x % 10).argsort_axis(Axis(2));
+    assert_eq!(a.take_along_axis(Axis(2), &order), a);
+
+    let rev = a.slice(s![.., .., ..;-1]);
+    assert_eq!(rev.take_along_axis(Axis(2), &rev.argsort_axis(Axis(2))), a);
+
+    let idx = arr3(&[[[1, 1, 0, 1]], [[0, 2, 2, 1]]]);
+    let taken = a.take_along_axis(Axis(1), &idx);
+    assert_eq!(taken, arr3(&[[[10, 11, 2, 13]], [[100, 121, 122, 113]]]));
+
+    let empty = Array3::<usize>::zeros((2, 3, 0));
+    assert_eq!(a.take_along_axis(Axis(2), &empty).shape(), &[2, 3, 0]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn take_along_axis_out_of_bounds() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    a.take_along_axis(Axis(1), &arr2(&[[0], [2]]));
+}
+
+#[test]
+#[should_panic(expected = "incompatible")]
+fn take_along_axis_incompatible_shape() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    a.take_along_axis(Axis(1), &arr2(&[[0, 1, 0]]));
+}
+
+#[test]
+fn put_along_axis() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let order = a.slice(s![.., .., ..;-1]).argsort_axis(Axis(2));
+    let mut b = Array3::zeros(a.raw_dim());
+    b.put_along_axis(Axis(2), &order, &a.take_along_axis(Axis(2), &order));
+    assert_eq!(b, a);
+
+    // last writer wins for duplicate indices
+    let mut c = arr2(&[[0, 0, 0], [0, 0, 0]]);
+    c.put_along_axis(Axis(1), &arr2(&[[1, 1], [2, 0]]), &arr2(&[[5, 6], [7, 8]]));
+    assert_eq!(c, arr2(&[[0, 6, 0], [8, 0, 7]]));
+
+    // values are broadcast to the shape of the indices
+    c.put_along_axis(Axis(0), &arr2(&[[1, 0, 1]]), &arr2(&[[9]]));
+    assert_eq!(c, arr2(&[[0, 9, 0], [9, 0, 9]]));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn put_along_axis_out_of_bounds() {
+    let mut a = arr2(&[[1, 2], [3, 4]]);
+    a.put_along_axis(Axis(0), &arr2(&[[0, 2]]), &arr2(&[[0, 0]]));
+}
+
+#[test]
+#[should_panic(expected = "broadcast")]
+fn put_along_axis_values_shape() {
+    let mut a = arr2(&[[1, 2], [3, 4]]);
+    a.put_along_axis(Axis(1), &arr2(&[[0], [1]]), &arr2(&[[0, 0], [1, 1]]));
+}
+
 #[test]
 fn array_macros() {
     // array
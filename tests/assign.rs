@@ -36,6 +36,34 @@ fn assign_to() {
     assert_eq!(a, b);
 }
 
+#[test]
+fn assign_scalar_where() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (4 * i + j) as i32);
+    let mask = a.mapv(|x| x % 3 == 0);
+    a.assign_scalar_where(&mask, -1);
+    assert_eq!(a, arr2(&[[-1, 1, 2, -1], [4, 5, -1, 7], [8, -1, 10, 11]]));
+
+    /* Test non-contiguous mask and array */
+    let mut a = Array::<i32, _>::zeros((4, 6));
+    let mask = Array::from_shape_fn((3, 3).f(), |(i, j)| i == j);
+    a.slice_mut(s![1.., ..;2]).assign_scalar_where(&mask, 1);
+    assert_eq!(a.sum(), 3);
+    assert_eq!(a[[1, 0]], 1);
+    assert_eq!(a[[2, 2]], 1);
+    assert_eq!(a[[3, 4]], 1);
+
+    /* Test empty mask */
+    let mut a = Array::<i32, _>::zeros((0, 3));
+    a.assign_scalar_where(&Array::from_elem((0, 3), true), 1);
+}
+
+#[test]
+#[should_panic(expected = "does not match array of shape")]
+fn assign_scalar_where_shape_mismatch() {
+    let mut a = Array::<i32, _>::zeros((2, 3));
+    a.assign_scalar_where(&Array::from_elem((3, 2), true), 1);
+}
+
 #[test]
 fn move_into_copy() {
     let a = arr2(&[[1., 2.], [3., 4.]]);
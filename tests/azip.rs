@@ -437,6 +437,56 @@ fn test_indices_split_1() {
     }
 }
 
+#[test]
+fn test_zip_and_mut() {
+    let a = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as f32);
+    let mut b = Array::<f32, _>::zeros((4, 5));
+    let mut c = Array::<f32, _>::ones((4, 5).f());
+    Zip::from(&a)
+        .and_mut(&mut b)
+        .and_mut(&mut c.view_mut())
+        .for_each(|&a, b, c| {
+            *b = 2. * a;
+            *c += a;
+        });
+    assert_eq!(b, &a * 2.);
+    assert_eq!(c, &a + 1.);
+}
+
+#[test]
+#[should_panic]
+fn test_zip_and_mut_shape_mismatch() {
+    let a = Array::<f32, _>::zeros((4, 5));
+    let mut b = Array::<f32, _>::zeros((5, 4));
+    let _ = Zip::from(&a).and_mut(&mut b);
+}
+
+#[test]
+fn test_split_recursive() {
+    fn visit(zip: Zip<(ArrayViewMut3<'_, usize>,), Ix3>) {
+        if zip.size() <= 1 {
+            zip.for_each(|x| *x += 1);
+        } else {
+            let (a, b) = zip.split();
+            assert!(a.size() > 0 && b.size() > 0);
+            visit(a);
+            visit(b);
+        }
+    }
+    let mut a = Array::zeros((3, 5, 4));
+    visit(Zip::from(a.view_mut()));
+    assert!(a.iter().all(|&x| x == 1));
+    visit(Zip::from(a.view_mut().reversed_axes()));
+    assert!(a.iter().all(|&x| x == 2));
+}
+
+#[test]
+#[should_panic]
+fn test_split_one_element() {
+    let a = Array::<f32, _>::zeros((1, 1));
+    let _ = Zip::from(&a).split();
+}
+
 #[test]
 fn test_zip_all() {
     let a = Array::<f32, _>::zeros(62);
@@ -455,3 +505,33 @@ fn test_zip_all_empty_array() {
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| true));
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| false));
 }
+
+#[test]
+fn test_zip_any() {
+    let a = Array::<f32, _>::zeros(62);
+    let b = Array::<f32, _>::ones(62);
+    let mut c = Array::<f32, _>::ones(62);
+    c[5] = 0.0;
+    assert!(Zip::from(&a).and(&b).any(|&x, &y| x + y == 1.0));
+    assert!(!Zip::from(&a).and(&b).any(|&x, &y| x == y));
+    assert!(Zip::from(&a).and(&c).any(|&x, &y| x == y));
+}
+
+#[test]
+fn test_zip_any_short_circuit() {
+    let a = Array::from_iter(0..10);
+    let mut visited = 0;
+    assert!(Zip::from(&a).any(|&x| {
+        visited += 1;
+        x == 3
+    }));
+    assert_eq!(visited, 4);
+}
+
+#[test]
+fn test_zip_any_empty_array() {
+    let a = Array::<f32, _>::zeros(0);
+    let b = Array::<f32, _>::ones(0);
+    assert!(!Zip::from(&a).and(&b).any(|&_x, &_y| true));
+    assert!(!Zip::from(&a).and(&b).any(|&_x, &_y| false));
+}
@@ -81,3 +81,23 @@ fn test_broadcast_1d() {
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn test_broadcast_with() {
+    let a = Array::from_shape_fn((3, 1), |(i, _)| i);
+    let b = array![10, 20];
+    let (va, vb) = a.broadcast_with(&b).unwrap();
+    assert_eq!(va, array![[0, 0], [1, 1], [2, 2]]);
+    assert_eq!(vb, array![[10, 20], [10, 20], [10, 20]]);
+    assert_eq!(va.strides(), &[1, 0]);
+    assert_eq!(vb.strides(), &[0, 1]);
+
+    // dynamic dimensions give a dynamic result
+    let a_dyn = a.view().into_dyn();
+    let (va, vb) = a_dyn.broadcast_with(&b).unwrap();
+    assert_eq!(va.shape(), &[3, 2]);
+    assert_eq!(vb.shape(), &[3, 2]);
+
+    let c = Array::<f64, _>::zeros((2, 3));
+    assert_eq!(a.broadcast_with(&c).unwrap_err().kind(), ndarray::ErrorKind::IncompatibleShape);
+}
@@ -12,3 +12,44 @@ fn test_clone_from() {
     bv.clone_from(&a.view());
     assert_eq!(&a, &bv);
 }
+
+#[test]
+fn test_clone_generic_via_view() {
+    use ndarray::{ArrayBase, Data, Dimension};
+
+    // Generic code over any `S: Data`, including mutable views, duplicates the
+    // array by reborrowing it as a read-only view.
+    fn pair<S, D>(a: &ArrayBase<S, D>) -> (ndarray::ArrayView<'_, i32, D>, ndarray::ArrayView<'_, i32, D>)
+    where
+        S: Data<Elem = i32>,
+        D: Dimension,
+    {
+        let v = a.view();
+        (v.clone(), v)
+    }
+
+    let mut a = arr2(&[[1, 2], [3, 4]]);
+    let (x, y) = pair(&a);
+    assert_eq!(x, y);
+    let m = a.view_mut();
+    let (x, y) = pair(&m);
+    assert_eq!(x, arr2(&[[1, 2], [3, 4]]));
+    assert_eq!(y.to_owned(), m.to_owned());
+}
+
+#[test]
+fn test_shallow_clone() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]).into_shared();
+    let mut b = a.shallow_clone();
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a, b);
+    // copy on write
+    b[[0, 0]] = 0;
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a[[0, 0]], 1);
+
+    let v = a.slice(ndarray::s![.., 1..]);
+    let w = v.shallow_clone();
+    assert_eq!(v.as_ptr(), w.as_ptr());
+    assert_eq!(w.shape(), &[2, 2]);
+}
@@ -1,5 +1,5 @@
 use ndarray::Array;
-use ndarray::{arr1, arr2, Axis};
+use ndarray::{arr1, arr2, s, Axis};
 use num_complex::Complex;
 use num_traits::Num;
 
@@ -21,3 +21,55 @@ fn complex_mat_mul() {
         arr1(&[c(1.5, 1.), c(2.5, 0.)])
     );
 }
+
+#[test]
+fn complex_re_im() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| c(i as f64, j as f64));
+    assert_eq!(a.re(), Array::from_shape_fn((3, 4), |(i, _)| i as f64));
+    assert_eq!(a.im(), Array::from_shape_fn((3, 4), |(_, j)| j as f64));
+    assert_eq!(a.re().strides(), &[8, 2]);
+
+    let v = a.slice(s![..;-1, 1..;2]);
+    assert_eq!(v.re(), v.map(|z| z.re));
+    assert_eq!(v.im(), v.map(|z| z.im));
+}
+
+#[test]
+fn complex_as_real_slice() {
+    let a = arr2(&[[c(1, 2), c(3, 4)], [c(5, 6), c(7, 8)]]);
+    assert_eq!(a.as_real_slice(), Some(&[1, 2, 3, 4, 5, 6, 7, 8][..]));
+    assert_eq!(a.row(1).as_real_slice(), Some(&[5, 6, 7, 8][..]));
+    assert_eq!(a.column(1).as_real_slice(), None);
+    assert_eq!(a.t().as_real_slice(), None);
+
+    let e = Array::<Complex<f32>, _>::zeros((0, 3));
+    assert_eq!(e.as_real_slice(), Some(&[][..]));
+}
+
+#[test]
+fn complex_conj() {
+    let a = arr2(&[[c(1., 2.), c(-3., 0.)], [c(0., -4.), c(5., 6.)]]);
+    let expected = arr2(&[[c(1., -2.), c(-3., 0.)], [c(0., 4.), c(5., -6.)]]);
+    assert_eq!(a.conj(), expected);
+    assert_eq!(a.conj().conj(), a);
+
+    let mut b = a.clone();
+    b.conj_inplace();
+    assert_eq!(b, expected);
+
+    let mut b = a.clone();
+    b.slice_mut(s![.., 1]).conj_inplace();
+    assert_eq!(b, arr2(&[[c(1., 2.), c(-3., 0.)], [c(0., -4.), c(5., -6.)]]));
+}
+
+#[test]
+fn complex_conj_transpose() {
+    let a = arr2(&[[c(1, 2), c(3, -4), c(0, 1)], [c(-5, 0), c(6, 7), c(8, -9)]]);
+    let h = a.conj_transpose();
+    assert_eq!(h, arr2(&[[c(1, -2), c(-5, 0)], [c(3, 4), c(6, -7)], [c(0, -1), c(8, 9)]]));
+    assert_eq!(h.conj_transpose(), a);
+
+    // a matrix times its adjoint is hermitian
+    let p = a.dot(&h);
+    assert_eq!(p.conj_transpose(), p);
+}
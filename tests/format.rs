@@ -1,5 +1,6 @@
 use ndarray::prelude::*;
-use ndarray::rcarr1;
+use ndarray::{rcarr1, FormatOptions};
+use std::fmt;
 
 #[test]
 fn formatting() {
@@ -72,3 +73,207 @@ fn debug_format() {
  [0, 0, 0, 0]], shape=[3, 4], strides=[4, 1], layout=Cc (0x5), dynamic ndim=2"
     );
 }
+
+#[test]
+fn debug_values() {
+    let a = Array2::<i32>::zeros((3, 4));
+    assert_eq!(
+        format!("{:?}", a.debug_values()),
+        "\
+[[0, 0, 0, 0],
+ [0, 0, 0, 0],
+ [0, 0, 0, 0]]"
+    );
+    assert_eq!(format!("{:?}", a.t().into_dyn().debug_values()), format!("{:?}", a.t().debug_values()));
+
+    let b = arr1(&[Some(1.5), None]);
+    assert_eq!(format!("{:?}", b.debug_values()), "[Some(1.5), None]");
+    assert_eq!(format!("{:.2?}", b.debug_values()), "[Some(1.50), None]");
+
+    let c = Array::from_shape_fn(1000, |i| i);
+    assert!(format!("{:?}", c.debug_values()).ends_with("..., 995, 996, 997, 998, 999]"));
+    assert!(format!("{:#?}", c.debug_values()).ends_with("997, 998, 999]"));
+    assert!(!format!("{:#?}", c.debug_values()).contains("..."));
+}
+
+#[test]
+fn decimal_align() {
+    let opts = FormatOptions::new().decimal_align(true);
+    let a = array![[1.5, -20.25, 3.], [f64::NAN, 0.125, -1e3]];
+    assert_eq!(
+        format!("{}", a.display_with(&opts)),
+        "\
+[[    1.5  ,   -20.25 ,     3    ],
+ [  NaN    ,     0.125, -1000    ]]"
+    );
+    assert_eq!(
+        format!("{:+.2}", a.display_with(&opts)),
+        "\
+[[   +1.50,   -20.25,    +3.00],
+ [  NaN   ,    +0.12, -1000.00]]"
+    );
+
+    // without the option, it is the same as Display
+    let default = FormatOptions::new();
+    assert_eq!(format!("{:6.1}", a.display_with(&default)), format!("{:6.1}", a));
+
+    // integers are right-aligned
+    let b = arr1(&[1, -200, 30]);
+    assert_eq!(format!("{}", b.display_with(&opts)), "[   1, -200,   30]");
+
+    // elided elements do not contribute to the alignment
+    let c = Array::from_shape_fn(1000, |i| if i == 500 { 1e6 } else { i as f64 / 4. });
+    assert_eq!(
+        format!("{}", c.display_with(&opts)),
+        "[  0   ,   0.25,   0.5 ,   0.75,   1   , ..., 248.75, 249   , 249.25, 249.5 , 249.75]"
+    );
+}
+
+#[test]
+fn sign_space() {
+    let opts = FormatOptions::new().sign_space(true);
+    let a = array![[1.5, -2.], [0., -0.25]];
+    assert_eq!(format!("{}", a.display_with(&opts)), "[[ 1.5, -2],\n [ 0, -0.25]]");
+    // the space counts towards the width
+    assert_eq!(format!("{:5}", a.display_with(&opts)), "[[  1.5,    -2],\n [    0, -0.25]]");
+    assert_eq!(format!("{:<5.1}", a.display_with(&opts)), "[[ 1.5 , -2.0 ],\n [ 0.0 , -0.2 ]]");
+    // an explicit plus sign takes the place of the space
+    assert_eq!(format!("{:+}", a.display_with(&opts)), "[[+1.5, -2],\n [+0, -0.25]]");
+
+    let both = opts.decimal_align(true);
+    assert_eq!(format!("{}", a.display_with(&both)), "[[ 1.5 , -2   ],\n [ 0   , -0.25]]");
+    let b = array![10., 2.5];
+    assert_eq!(format!("{}", b.display_with(&both)), "[ 10  ,   2.5]");
+}
+
+#[test]
+fn elements_limit() {
+    let a = Array::from_shape_fn((3, 5), |(i, j)| 10 * i + j);
+    let opts = FormatOptions::new().elements_limit(2);
+    assert_eq!(format!("{}", a.display_with(&opts)), "[[0, ..., 4],\n ...,\n [20, ..., 24]]");
+    assert_eq!(format!("{:#}", a.display_with(&opts)), format!("{}", a));
+    let opts = FormatOptions::new().elements_limit(0);
+    assert_eq!(format!("{}", a.display_with(&opts)), "[[0, ...],\n ...]");
+}
+
+#[test]
+fn summarize_to_width() {
+    let a = Array::from_shape_fn((4, 6), |(i, j)| 10 * i + j);
+    let full = format!("{}", a);
+    assert_eq!(a.summarize_to_width(full.len()), full);
+
+    for max_chars in 0..full.len() {
+        let s = a.summarize_to_width(max_chars);
+        if s.starts_with('<') {
+            assert_eq!(s, "<Array shape=[4, 6]>");
+            assert!(max_chars < "[[0, ...],\n ...]".len());
+        } else {
+            assert!(s.chars().count() <= max_chars);
+            assert!(s.contains("..."));
+        }
+    }
+    assert_eq!(a.summarize_to_width(40), "[[0, ..., 5],\n ...,\n [30, ..., 35]]");
+
+    assert_eq!(arr0(1.5).summarize_to_width(3), "1.5");
+    assert_eq!(arr0(1.5).summarize_to_width(2), "<Array shape=[]>");
+    assert_eq!(Array2::<f32>::zeros((0, 3)).summarize_to_width(4), "[[]]");
+}
+
+#[test]
+fn decimal_align_visits_printed_elements_only() {
+    use std::cell::Cell;
+    use std::fmt;
+
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl fmt::Display for Counted<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            f.write_str("1.5")
+        }
+    }
+
+    let count = Cell::new(0);
+    let elt = Counted(&count);
+    let a = aview0(&elt);
+    let a = a.broadcast((1000, 1000, 1000)).unwrap();
+    let opts = FormatOptions::new().decimal_align(true);
+    let s = format!("{}", a.display_with(&opts));
+    // 6 x 10 x 10 elements are printed; each is rendered once to measure it
+    // and once to write it.
+    assert_eq!(s.matches("1.5").count(), 600);
+    assert_eq!(count.get(), 2 * 600);
+}
+
+#[test]
+fn format_with_index() {
+    let a = Array::from_shape_fn((2, 3, 1000), |(i, j, k)| 10000 * i + 1000 * j + k);
+    let opts = FormatOptions::new();
+    // the index matches the element, also for elements after an ellipsis
+    let checked = a.format_with_index(&opts, |(i, j, k), &x, f| {
+        assert_eq!(x, a[(i, j, k)]);
+        write!(f, "{}", x)
+    });
+    assert_eq!(checked.to_string(), a.to_string());
+
+    let d = a.view().into_dyn();
+    let checked = d.format_with_index(&opts, |index, &x, f| {
+        assert_eq!(x, d[index.slice()]);
+        write!(f, "{}", x)
+    });
+    assert_eq!(checked.to_string(), a.to_string());
+
+    // options and formatting parameters apply
+    let b = arr1(&[1.5, -20., 3.25]);
+    let opts = FormatOptions::new().decimal_align(true);
+    let s = b.format_with_index(&opts, |i, x, f| {
+        if i == 1 {
+            write!(f, "*")?;
+        }
+        fmt::Display::fmt(x, f)
+    });
+    assert_eq!(format!("{:.1}", s), "[   1.5, *-20.0,    3.2]");
+
+    let c = arr0(5);
+    assert_eq!(c.format_with_index(&FormatOptions::new(), |(), x, f| write!(f, "({})", x)).to_string(), "(5)");
+}
+
+#[test]
+fn sparkline() {
+    let a = Array::from_shape_fn(15, |i| i as f64 - 7.);
+    assert_eq!(a.sparkline(), "▁▂▂▃▃▄▄▅▅▆▆▇▇██");
+    assert_eq!(a.slice(s![..;-2]).sparkline(), "█▇▆▅▄▃▂▁");
+
+    let b = arr1(&[f64::NAN, 1., f64::INFINITY, 3., f64::NEG_INFINITY]);
+    assert_eq!(b.sparkline(), " ▁██▁");
+    assert_eq!(arr1(&[5., f64::NAN, f64::INFINITY]).sparkline(), "▄ █");
+    assert_eq!(arr1(&[f64::NAN]).sparkline(), " ");
+    assert_eq!(Array1::<f64>::zeros(0).sparkline(), "");
+}
+
+#[test]
+fn heatmap_string() {
+    use ndarray::ColorMap;
+
+    let cell = |r: u8, g: u8, b: u8| format!("\x1b[48;2;{};{};{}m ", r, g, b);
+    let a = arr2(&[[-1., 3.], [f64::INFINITY, f64::NAN], [1., f64::NEG_INFINITY]]);
+    let expected = [
+        cell(0, 0, 0) + &cell(255, 255, 255),
+        cell(255, 255, 255) + &cell(255, 0, 0),
+        cell(128, 128, 128) + &cell(0, 0, 0),
+    ]
+    .iter()
+    .map(|row| row.clone() + "\x1b[0m\n")
+    .collect::<String>();
+    assert_eq!(a.heatmap_string(ColorMap::Gray), expected);
+
+    // viridis goes from dark purple to yellow
+    let b = arr2(&[[0., 0.5, 1.]]);
+    let expected = cell(68, 1, 84) + &cell(33, 145, 140) + &cell(253, 231, 37) + "\x1b[0m\n";
+    assert_eq!(b.heatmap_string(ColorMap::Viridis), expected);
+    assert_eq!(b.t().heatmap_string(ColorMap::Viridis).lines().count(), 3);
+
+    let c = Array2::<f64>::from_elem((1, 2), 7.);
+    assert_eq!(c.heatmap_string(ColorMap::Viridis), cell(33, 145, 140).repeat(2) + "\x1b[0m\n");
+    assert_eq!(Array2::<f64>::zeros((0, 3)).heatmap_string(ColorMap::Gray), "");
+}
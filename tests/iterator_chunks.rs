@@ -99,3 +99,24 @@ fn chunks_different_size_3() {
     let mut a = Array::<f32, _>::zeros(vec![2, 3]);
     a.exact_chunks_mut(vec![2, 3, 4]);
 }
+
+#[test]
+fn chunk_reduce() {
+    let a = Array::from_shape_fn((5, 7), |(i, j)| 10 * i + j);
+    let sums = a.chunk_reduce((2, 3), |chunk| chunk.sum());
+    assert_eq!(sums, array![[36, 54], [156, 174]]);
+
+    let maxes = a.t().chunk_reduce((3, 5), |chunk| *chunk.iter().max().unwrap());
+    assert_eq!(maxes, array![[42], [45]]);
+
+    let d = a.view().into_dyn();
+    assert_eq!(d.chunk_reduce(vec![1, 7], |chunk| chunk.len()).shape(), &[5, 1]);
+    assert_eq!(a.chunk_reduce((6, 1), |chunk| chunk.sum()).shape(), &[0, 7]);
+}
+
+#[should_panic]
+#[test]
+fn chunk_reduce_zero_size() {
+    let a = Array::<f32, _>::zeros((3, 4));
+    a.chunk_reduce((2, 0), |chunk| chunk.sum());
+}
@@ -39,6 +39,28 @@ fn double_ended() {
     assert_equal(aview1(&[1, 2, 3]).into_iter().rev(), [1, 2, 3].iter().rev());
 }
 
+#[test]
+fn iter_logical_and_memory() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    assert_equal(a.iter_logical(), a.iter());
+    assert_equal(a.iter_memory(), a.as_slice().unwrap());
+
+    let f = a.t().to_owned();
+    assert_equal(f.iter_logical(), a.t().iter());
+    assert_equal(f.iter_memory(), f.as_slice_memory_order().unwrap());
+
+    let p = a.view().permuted_axes([1, 2, 0]);
+    assert_equal(p.iter_memory(), a.iter());
+
+    let r = a.slice(s![..;-1, .., ..;-2]);
+    let mut elems = r.iter_memory().collect::<Vec<_>>();
+    assert!(elems.windows(2).all(|w| (w[0] as *const usize) < (w[1] as *const usize)));
+    elems.sort();
+    let mut expected = r.iter().collect::<Vec<_>>();
+    expected.sort();
+    assert_eq!(elems, expected);
+}
+
 #[test]
 fn iter_size_hint() {
     // Check that the size hint is correctly computed
@@ -377,6 +399,33 @@ fn outer_iter_mut() {
     assert_equal(a.rows(), found_rows);
 }
 
+#[test]
+fn outer_iter_mut_disjoint() {
+    // The subviews cover distinct elements, also with negative and non-unit
+    // strides, so they can be mutated independently while all alive.
+    let mut a = Array::from_shape_fn((6, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let expected = a.mapv(|x| x + 1000 * (x / 100 + 1));
+    let mut rev = a.slice_mut(s![..;-1, .., ..]);
+    let mut views: Vec<_> = rev.outer_iter_mut().collect();
+    assert_eq!(views.len(), 6);
+    let ptrs: Vec<*const usize> = views.iter().flat_map(|v| v.iter().map(|x| x as *const _)).collect();
+    let mut unique = ptrs.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), ptrs.len());
+    for (i, v) in views.iter_mut().enumerate() {
+        v.map_inplace(|x| *x += 1000 * (6 - i));
+    }
+    assert_eq!(a, expected);
+
+    let mut b = Array::<u8, _>::zeros((4, 5).f());
+    let mut rows: Vec<_> = b.outer_iter_mut().collect();
+    for (i, row) in rows.iter_mut().enumerate() {
+        row.fill(i as u8);
+    }
+    assert_eq!(b, Array::from_shape_fn((4, 5), |(i, _)| i as u8));
+}
+
 #[test]
 fn axis_iter_mut() {
     let a = ArcArray::from_iter(0..12);
@@ -781,6 +830,36 @@ fn test_fold() {
     assert_eq!(a.iter().fold(0, |acc, &x| acc + x), 1);
 }
 
+#[test]
+fn test_fold_while() {
+    use ndarray::FoldWhile;
+
+    let a = Array::from_shape_fn((4, 5).f(), |(i, j)| 5 * i + j);
+    // visits in logical order and stops at the first Done
+    let mut seen = Vec::new();
+    let r = a.fold_while(0, |acc, &x| {
+        seen.push(x);
+        if acc + x >= 10 {
+            FoldWhile::Done(acc + x)
+        } else {
+            FoldWhile::Continue(acc + x)
+        }
+    });
+    assert!(r.is_done());
+    assert_eq!(r.into_inner(), 10);
+    assert_eq!(seen, [0, 1, 2, 3, 4]);
+
+    let r = a.slice(s![..;-1, ..]).fold_while(Vec::new(), |mut v, &x| {
+        v.push(x);
+        FoldWhile::Continue(v)
+    });
+    assert!(!r.is_done());
+    assert_eq!(r.into_inner(), a.slice(s![..;-1, ..]).iter().cloned().collect::<Vec<_>>());
+
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.fold_while(7, |_, _| FoldWhile::Done(0)).into_inner(), 7);
+}
+
 #[test]
 fn nth_back_examples() {
     let mut a: Array1<i32> = (0..256).collect();
@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, NpyError};
+
+/// Build an `.npy` file (format version 1.0) from a header dictionary and raw data.
+fn npy_file(header: &str, data: &[u8]) -> Vec<u8> {
+    let mut header = header.to_string();
+    let padding = 63 - (10 + header.len()) % 64;
+    for _ in 0..padding {
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut file = b"\x93NUMPY\x01\x00".to_vec();
+    file.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    file.extend_from_slice(header.as_bytes());
+    file.extend_from_slice(data);
+    file
+}
+
+#[test]
+fn write_header() {
+    let a = array![[1i32, 2, 3], [4, 5, 6]];
+    let mut file = Vec::new();
+    a.write_npy(&mut file).unwrap();
+    let data: Vec<u8> = (1..=6i32).flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(file, npy_file("{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }", &data));
+
+    let mut file = Vec::new();
+    a.row(1).write_npy(&mut file).unwrap();
+    let data: Vec<u8> = (4..=6i32).flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(file, npy_file("{'descr': '<i4', 'fortran_order': False, 'shape': (3,), }", &data));
+}
+
+#[test]
+fn round_trip() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (100 * i + 10 * j + k) as f64);
+    let mut file = Vec::new();
+    a.write_npy(&mut file).unwrap();
+    assert_eq!(file.len() % 64, (a.len() * 8) % 64);
+    assert_eq!(Array3::<f64>::read_npy(&file[..]).unwrap(), a);
+    assert_eq!(ArrayD::<f64>::read_npy(&file[..]).unwrap(), a.clone().into_dyn());
+
+    // fortran layout is kept
+    let f = a.t().to_owned();
+    let mut file = Vec::new();
+    f.t().write_npy(&mut file).unwrap();
+    let b = Array3::<f64>::read_npy(&file[..]).unwrap();
+    assert_eq!(b, a);
+    assert_eq!(b.strides(), f.t().strides());
+
+    // non-contiguous views are written in logical order
+    let v = a.slice(s![..;-1, 1.., ..;2]);
+    let mut file = Vec::new();
+    v.write_npy(&mut file).unwrap();
+    assert_eq!(Array3::<f64>::read_npy(&file[..]).unwrap(), v);
+
+    let s = arr0(7i64);
+    let mut file = Vec::new();
+    s.write_npy(&mut file).unwrap();
+    assert_eq!(Array0::<i64>::read_npy(&file[..]).unwrap(), s);
+
+    let e = Array2::<f32>::zeros((0, 3));
+    let mut file = Vec::new();
+    e.write_npy(&mut file).unwrap();
+    assert_eq!(Array2::<f32>::read_npy(&file[..]).unwrap(), e);
+}
+
+#[test]
+fn read_fortran_big_endian() {
+    // np.array([[1., 2., 3.], [4., 5., 6.]], dtype='>f4', order='F')
+    let data: Vec<u8> = [1f32, 4., 2., 5., 3., 6.].iter().flat_map(|x| x.to_be_bytes()).collect();
+    let file = npy_file("{'descr': '>f4', 'fortran_order': True, 'shape': (2, 3), }", &data);
+    let a = Array2::<f32>::read_npy(&file[..]).unwrap();
+    assert_eq!(a, array![[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(a.strides(), &[1, 2]);
+}
+
+#[test]
+fn read_errors() {
+    let data: Vec<u8> = (1..=6i32).flat_map(|x| x.to_le_bytes()).collect();
+    let file = npy_file("{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }", &data);
+
+    match Array2::<f64>::read_npy(&file[..]) {
+        Err(NpyError::DtypeMismatch(found, expected)) => {
+            assert_eq!(found, "<i4");
+            assert_eq!(expected, "f8");
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    match Array1::<i32>::read_npy(&file[..]) {
+        Err(NpyError::Shape(err)) => assert_eq!(err.kind(), ErrorKind::IncompatibleShape),
+        res => panic!("unexpected result {:?}", res),
+    }
+    match Array2::<i32>::read_npy(&file[..file.len() - 1]) {
+        Err(NpyError::Io(_)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    let mut bad_magic = file.clone();
+    bad_magic[1] = b'X';
+    assert!(matches!(Array2::<i32>::read_npy(&bad_magic[..]), Err(NpyError::MagicString)));
+
+    let mut bad_version = file.clone();
+    bad_version[6] = 4;
+    assert!(matches!(Array2::<i32>::read_npy(&bad_version[..]), Err(NpyError::Version(4, 0))));
+
+    let bad_header = npy_file("{'descr': '<i4', 'shape': (2, 3), }", &data);
+    assert!(matches!(Array2::<i32>::read_npy(&bad_header[..]), Err(NpyError::Header(_))));
+
+    // a shape whose size in bytes overflows
+    let huge = format!("{{'descr': '<i8', 'fortran_order': False, 'shape': ({},), }}", isize::MAX as usize / 2);
+    match Array1::<i64>::read_npy(&npy_file(&huge, &data)[..]) {
+        Err(NpyError::Shape(err)) => assert_eq!(err.kind(), ErrorKind::Overflow),
+        res => panic!("unexpected result {:?}", res),
+    }
+
+    // a large shape with little data fails without allocating for the whole shape
+    let large = npy_file("{'descr': '<i4', 'fortran_order': False, 'shape': (65536, 65536), }", &data);
+    match Array2::<i32>::read_npy(&large[..]) {
+        Err(NpyError::Io(err)) => assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof),
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn write_large() {
+    // more data than fits in the write buffer
+    let a = Array::from_shape_fn((100, 70), |(i, j)| (i * 70 + j) as i64);
+    let mut file = Vec::new();
+    a.t().write_npy(&mut file).unwrap();
+    assert_eq!(file.len(), 128 + a.len() * 8);
+    assert_eq!(Array2::<i64>::read_npy(&file[..]).unwrap(), a.t());
+}
@@ -7,7 +7,7 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{arr0, arr1, arr2, array, aview1, s, Array, Array1, Array2, Array3, Axis, ConvMode};
 use std::f64;
 
 #[test]
@@ -307,3 +307,583 @@ fn std_axis_empty_axis() {
     assert_eq!(v.shape(), &[2]);
     v.mapv(|x| assert!(x.is_nan()));
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn all_close() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    let mut b = a.t().to_owned();
+    b.swap_axes(0, 1);
+    b[[2, 3]] += 0.25;
+    assert!(a.all_close(&b, 0.25));
+    assert!(!a.all_close(&b, 0.125));
+    assert!(b.all_close(&a, 0.25));
+
+    let a = array![f64::INFINITY, -f64::INFINITY, 0.];
+    assert!(a.all_close(&a, 0.));
+    assert!(!a.all_close(&array![f64::INFINITY, f64::INFINITY, 0.], 1e10));
+
+    let a = Array2::<f32>::zeros((0, 3));
+    assert!(a.all_close(&a, 0.));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn all_close_with() {
+    let a = array![100., 1., f64::NAN];
+    let b = array![101., 1.02, f64::NAN];
+    assert!(!a.all_close_with(&b, 0., 0.01, false));
+    assert!(a.all_close_with(&b, 0., 0.02, true));
+    assert!(!a.all_close_with(&b, 0., 0.01, true));
+    assert!(a.all_close_with(&b, 0.02, 0.01, true));
+
+    // NaN is only close to NaN if `equal_nan` is true
+    let a = array![f64::NAN];
+    assert!(!a.all_close_with(&a, 1., 1., false));
+    assert!(a.all_close_with(&a, 1., 1., true));
+    assert!(!a.all_close_with(&array![0.], 1., 1., true));
+
+    // infinity is only close to the same infinity, even with a relative tolerance
+    let inf = array![f64::INFINITY, f64::NEG_INFINITY];
+    assert!(inf.all_close_with(&inf, 1., 1., false));
+    assert!(!array![1.].all_close_with(&array![f64::INFINITY], 0., 0.5, false));
+    assert!(!array![f64::INFINITY].all_close_with(&array![1.], 0., 0.5, false));
+    assert!(!array![f64::NEG_INFINITY].all_close_with(&array![f64::INFINITY], 1., 1., false));
+    assert!(!array![f64::MAX].all_close_with(&array![f64::INFINITY], 1., 1., false));
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn all_close_shape_mismatch() {
+    let a = Array2::<f64>::zeros((2, 3));
+    a.all_close(&Array2::zeros((3, 2)), 0.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nan_to_num() {
+    let a = array![[1., f64::NAN], [f64::INFINITY, f64::NEG_INFINITY]];
+    let expected = array![[1., 0.], [1e300, -1e300]];
+    assert_eq!(a.nan_to_num(0., 1e300, -1e300), expected);
+
+    let mut b = a.t().to_owned();
+    b.nan_to_num_inplace(0., 1e300, -1e300);
+    assert_eq!(b, expected.t());
+
+    let c = array![-0.5f32, 2.5];
+    assert_eq!(c.nan_to_num(0., 0., 0.), c);
+}
+
+#[test]
+fn min_max() {
+    let a = array![[3, 1, 4], [1, 5, 9], [2, 6, 5]];
+    assert_eq!(a.min_element(), Some(&1));
+    assert_eq!(a.max_element(), Some(&9));
+    assert_eq!(a.minmax_element(), Some((&1, &9)));
+    assert_eq!(a.t().slice(s![1.., ..;-1]).minmax_element(), Some((&1, &9)));
+
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.min_element(), None);
+    assert_eq!(e.max_element(), None);
+    assert_eq!(e.minmax_element(), None);
+
+    // elements that can not be compared
+    assert_eq!(array![f64::NAN].minmax_element(), None);
+    assert_eq!(array![1., f64::NAN, 3.].min_element(), None);
+    assert_eq!(array![1., 2., f64::NAN].max_element(), None);
+    assert_eq!(array![-0., 0.].minmax_element(), Some((&0., &0.)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn min_max_skipnan() {
+    let a = array![[f64::NAN, 1.], [-3., f64::NAN], [2., 0.]];
+    assert_eq!(a.min_skipnan(), Some(&-3.));
+    assert_eq!(a.max_skipnan(), Some(&2.));
+
+    let inf = array![f32::NEG_INFINITY, f32::NAN, f32::INFINITY];
+    assert_eq!(inf.min_skipnan(), Some(&f32::NEG_INFINITY));
+    assert_eq!(inf.max_skipnan(), Some(&f32::INFINITY));
+
+    let nan = Array::from_elem((2, 2), f64::NAN);
+    assert_eq!(nan.min_skipnan(), None);
+    assert_eq!(nan.max_skipnan(), None);
+    assert_eq!(Array1::<f64>::zeros(0).min_skipnan(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn normalize() {
+    let a = array![[1., 5.], [3., f64::NAN]];
+    let n = a.normalize();
+    assert_eq!(n.slice(s![.., 0]), array![0., 0.5]);
+    assert_eq!(n[[0, 1]], 1.);
+    assert!(n[[1, 1]].is_nan());
+
+    assert_eq!(Array2::from_elem((2, 3), -4.).normalize(), Array2::<f64>::zeros((2, 3)));
+    assert_eq!(Array1::<f32>::zeros(0).normalize(), Array1::<f32>::zeros(0));
+    assert!(array![f64::NAN].normalize()[0].is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn standardize() {
+    let a = Array::linspace(-3., 10., 27).into_shape((3, 9)).unwrap();
+    let s = a.t().standardize();
+    assert_abs_diff_eq!(s.mean().unwrap(), 0., epsilon = 1e-12);
+    assert_abs_diff_eq!(s.std(0.), 1., epsilon = 1e-12);
+    assert!(s.all_close(&((&a.t() - a.mean().unwrap()) / a.std(0.)), 1e-12));
+
+    assert_eq!(Array2::from_elem((2, 3), 2.5).standardize(), Array2::<f64>::zeros((2, 3)));
+    assert_eq!(Array1::<f64>::zeros(0).standardize(), Array1::<f64>::zeros(0));
+}
+
+#[test]
+fn pooling() {
+    let a = Array::from_shape_fn((4, 5), |(i, j)| (5 * i + j) as f64);
+    assert_eq!(a.max_pool2d((2, 2), 2), array![[6., 8.], [16., 18.]]);
+    assert_eq!(a.avg_pool2d((2, 2), 2), array![[3., 5.], [13., 15.]]);
+    assert_eq!(a.max_pool2d((3, 2), 1), array![[11., 12., 13., 14.], [16., 17., 18., 19.]]);
+    assert_eq!(a.avg_pool2d((4, 5), 3), array![[9.5]]);
+    assert_eq!(a.max_pool2d((1, 1), 3), array![[0., 3.], [15., 18.]]);
+    assert_eq!(a.avg_pool2d((5, 1), 1).shape(), &[0, 5]);
+
+    let mut b = a.clone();
+    b[[0, 0]] = f64::NAN;
+    let m = b.max_pool2d((2, 2), 2);
+    assert!(m[[0, 0]].is_nan());
+    assert_eq!(m.slice(s![1.., ..]), array![[16., 18.]]);
+}
+
+#[test]
+#[should_panic]
+fn pooling_zero_stride() {
+    let a = Array2::<f64>::zeros((4, 4));
+    a.max_pool2d((2, 2), 0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn histogram() {
+    let a = Array::linspace(0., 1., 11);
+    let (counts, edges) = a.histogram(10, (0., 1.));
+    assert_eq!(counts, Array1::from_elem(10, 1) + array![0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(edges, Array::linspace(0., 1., 11));
+
+    // values on the edges go into the bin that starts there
+    let b = array![0.25, 0.5, 0.75, 1.5, 1.75, 2.0, 2.25];
+    let (counts, _) = b.slice(s![..;-1]).histogram(8, (0., 2.));
+    assert_eq!(counts, array![0, 1, 1, 1, 0, 0, 1, 2]);
+
+    let (counts, edges) = b.histogram(1, (-1., 0.5));
+    assert_eq!(counts, array![2]);
+    assert_eq!(edges, array![-1., 0.5]);
+
+    // the width of the range overflows
+    let m = f64::MAX;
+    let (counts, edges) = array![-1e308, 0., 1e308, m, -m].histogram(4, (-m, m));
+    assert_eq!(counts, array![2, 0, 1, 2]);
+    assert!(edges.all_close_with(&array![-m, -m / 2., 0., m / 2., m], 0., 1e-15, false));
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn histogram_empty_range() {
+    array![1., 2.].histogram(2, (1., 1.));
+}
+
+#[test]
+fn bincount() {
+    let a = array![2, 0, 2, 5, 2];
+    assert_eq!(a.bincount(0), array![1, 0, 3, 0, 0, 1]);
+    assert_eq!(a.bincount(3), array![1, 0, 3, 0, 0, 1]);
+    assert_eq!(a.slice(s![..3]).bincount(4), array![1, 0, 2, 0]);
+    assert_eq!(Array1::<usize>::zeros(0).bincount(0), Array1::<usize>::zeros(0));
+    assert_eq!(Array1::<usize>::zeros(0).bincount(2), array![0, 0]);
+
+    let w = array![1, 2, 3, 4, 5];
+    assert_eq!(a.bincount_weighted(&w, 0), array![2, 0, 9, 0, 0, 4]);
+    assert_eq!(a.bincount_weighted(&w.mapv(|x| x as f64), 7), array![2., 0., 9., 0., 0., 4., 0.]);
+}
+
+#[test]
+#[should_panic]
+fn bincount_weighted_length_mismatch() {
+    array![0, 1].bincount_weighted(&array![1.], 0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn normalize_axis() {
+    use ndarray::NormKind;
+
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| (i * 8 + j * 2 + k) as f64 - 5.);
+    for &kind in &[NormKind::L1, NormKind::L2, NormKind::Max] {
+        for ax in 0..3 {
+            let n = a.normalize_axis(Axis(ax), kind);
+            for (lane, orig) in n.lanes(Axis(ax)).into_iter().zip(a.lanes(Axis(ax))) {
+                let norm = match kind {
+                    NormKind::L1 => lane.mapv(f64::abs).sum(),
+                    NormKind::L2 => lane.mapv(|x| x * x).sum().sqrt(),
+                    _ => lane.fold(0., |acc, x| f64::max(acc, x.abs())),
+                };
+                assert_abs_diff_eq!(norm, 1., epsilon = 1e-12);
+                assert!(lane.iter().zip(&orig).all(|(x, y)| x.signum() == y.signum()));
+            }
+        }
+    }
+
+    let z = array![[0., 0.], [1., f64::NAN]];
+    let n = z.t().normalize_axis(Axis(0), NormKind::L2);
+    assert_eq!(n.column(0), array![0., 0.]);
+    assert_eq!(n[[0, 1]], 1.);
+    assert!(n[[1, 1]].is_nan());
+    // lanes with NaN have a NaN max norm too, and are copied unchanged
+    let m = array![[2., f64::NAN], [2., -4.]].normalize_axis(Axis(1), NormKind::Max);
+    assert_eq!(m[[0, 0]], 2.);
+    assert!(m[[0, 1]].is_nan());
+    assert_eq!(m.row(1), array![0.5, -1.]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn softmax_axis() {
+    let a = Array::from_shape_fn((3, 5), |(i, j)| (i as f64 - 1.) * j as f64);
+    for ax in 0..2 {
+        let s = a.softmax_axis(Axis(ax));
+        for sum in s.sum_axis(Axis(ax)) {
+            assert_abs_diff_eq!(sum, 1., epsilon = 1e-12);
+        }
+        assert!(s.iter().all(|&x| x > 0.));
+    }
+    let s = a.softmax_axis(Axis(1));
+    let row = a.row(2).mapv(f64::exp);
+    assert!(s.row(2).all_close(&(&row / row.sum()), 1e-12));
+
+    // large inputs do not overflow
+    let big = array![[1000., 1001., 1002.], [-1000., -1001., -1002f64]];
+    let s = big.softmax_axis(Axis(1));
+    assert!(s.iter().all(|x| x.is_finite()));
+    assert!(s.row(0).all_close(&s.row(1).slice(s![..;-1]), 1e-12));
+    assert_abs_diff_eq!(s.row(0).sum(), 1., epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn norms() {
+    let a = Array::from_shape_fn((2, 3, 2), |(i, j, k)| (i as f64 - 0.5) * (j * 2 + k) as f64);
+    let v = Array::from_iter(a.iter().cloned());
+    assert_abs_diff_eq!(a.norm_l2(), v.dot(&v).sqrt(), epsilon = 1e-12);
+    assert_abs_diff_eq!(a.norm_l2_sq(), v.dot(&v), epsilon = 1e-12);
+    assert_eq!(a.norm_l1(), v.mapv(f64::abs).sum());
+    assert_eq!(a.norm_max(), 2.5);
+    assert_eq!(a.t().norm_l1(), a.norm_l1());
+
+    let e = Array2::<f32>::zeros((0, 3));
+    assert_eq!(e.norm_l2(), 0.);
+    assert_eq!(e.norm_max(), 0.);
+
+    // NaN propagates through every norm
+    for nan in &[array![f64::NAN, 1., -3.], array![1., f64::NAN, -3.], array![1., -3., f64::NAN]] {
+        assert!(nan.norm_max().is_nan());
+        assert!(nan.norm_l1().is_nan());
+        assert!(nan.norm_l2().is_nan());
+    }
+    assert_eq!(array![f64::NEG_INFINITY, 1.].norm_max(), f64::INFINITY);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn cov_and_corrcoef() {
+    let a: Array2<f64> = array![[1., 2., 3., 4., 7.], [2., 0., 6., 4., -1.], [0.5, 0.5, 0.5, 0.5, 0.5]];
+    let cov = a.cov(1.);
+    assert_eq!(cov.shape(), &[3, 3]);
+    for i in 0..3 {
+        assert!((cov[[i, i]] - a.row(i).var(1.)).abs() < 1e-12);
+        for j in 0..3 {
+            assert_eq!(cov[[i, j]], cov[[j, i]]);
+        }
+    }
+    // the cross covariance is computed around the means of the rows
+    let dx = a.row(0).mapv(|x| x - 3.4);
+    let dy = a.row(1).mapv(|y| y - 2.2);
+    assert!((cov[[0, 1]] - dx.dot(&dy) / 4.).abs() < 1e-12);
+    assert!(a.cov(0.).all_close(&(&cov * 4. / 5.), 1e-12));
+    // a constant variable has no covariance
+    assert_eq!(cov.row(2), array![0., 0., 0.]);
+
+    let corr = a.slice(s![..2, ..]).corrcoef();
+    let r = cov[[0, 1]] / (cov[[0, 0]] * cov[[1, 1]]).sqrt();
+    assert!(corr.all_close(&array![[1., r], [r, 1.]], 1e-12));
+    assert!(a.corrcoef().row(2).iter().all(|x| x.is_nan()));
+    assert_eq!(array![[1., 2.], [4., 2.]].corrcoef(), array![[1., -1.], [-1., 1.]]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn cov_one_observation() {
+    array![[1.], [2.]].cov(0.);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn cov_ddof_too_large() {
+    array![[1., 2.], [2., 3.]].cov(2.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_axis() {
+    use ndarray::QuantileMethod::*;
+
+    // the lanes along axis 1 are [0, 1, 2, 3, 4] and [10, 20, 30] in shuffled order
+    let a: Array1<f64> = array![3., 0., 4., 1., 2.];
+    let b: Array1<f64> = array![30., 10., 20.];
+    let quantile = |lane: &Array1<f64>, q: f64, method| lane.clone().insert_axis(Axis(0)).quantile_axis(Axis(1), q, method)[0];
+    // h = 0.3 * 4 = 1.2
+    assert_eq!(quantile(&a, 0.3, Linear), 1.2);
+    assert_eq!(quantile(&a, 0.3, Lower), 1.);
+    assert_eq!(quantile(&a, 0.3, Higher), 2.);
+    assert_eq!(quantile(&a, 0.3, Nearest), 1.);
+    assert_eq!(quantile(&a, 0.3, Midpoint), 1.5);
+    // h = 0.75 * 2 = 1.5: nearest rounds to the even index
+    assert_eq!(quantile(&b, 0.75, Nearest), 30.);
+    assert_eq!(quantile(&b, 0.25, Nearest), 10.);
+    assert_eq!(quantile(&b, 0.75, Linear), 25.);
+    for &method in &[Linear, Lower, Higher, Nearest, Midpoint] {
+        assert_eq!(quantile(&a, 0., method), 0.);
+        assert_eq!(quantile(&a, 1., method), 4.);
+        assert_eq!(quantile(&a, 0.5, method), 2.);
+    }
+
+    let m = Array::from_shape_fn((3, 4, 5), |(i, j, k)| ((7 * i + 3 * j + 11 * k) % 13) as f64);
+    let median = m.quantile_axis(Axis(2), 0.5, Linear);
+    assert_eq!(median.shape(), &[3, 4]);
+    for ((i, j), &med) in median.indexed_iter() {
+        let mut lane = m.slice(s![i, j, ..]).to_vec();
+        lane.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert_eq!(med, lane[2]);
+    }
+    let lower = m.quantile_axis(Axis(0), 0.4, Lower);
+    assert_eq!(lower, m.map_axis(Axis(0), |lane| *lane.min_element().unwrap()));
+
+    let n = array![[1., f64::NAN, 3.], [1., 2., 3.]];
+    let q = n.quantile_axis(Axis(1), 0.5, Linear);
+    assert!(q[0].is_nan());
+    assert_eq!(q[1], 2.);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn quantile_axis_bad_q() {
+    array![[1., 2.]].quantile_axis(Axis(1), 1.5, ndarray::QuantileMethod::Linear);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn quantile_axis_empty() {
+    Array2::<f64>::zeros((3, 0)).quantile_axis(Axis(1), 0.5, ndarray::QuantileMethod::Linear);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn median_axis() {
+    // odd lane length
+    let a = array![[5., -1., 3., 0., 8.], [2., 2., 2., 2., 2.]];
+    assert_eq!(a.median_axis(Axis(1)), array![3., 2.]);
+    // even lane length
+    let b = array![[4., 1., 3., 2.], [0., 10., -10., 1.]];
+    assert_eq!(b.median_axis(Axis(1)), array![2.5, 0.5]);
+    assert_eq!(b.median_axis(Axis(0)), array![2., 5.5, -3.5, 1.5]);
+    assert_eq!(b.t().median_axis(Axis(0)), b.median_axis(Axis(1)));
+    assert_eq!(arr1(&[7.]).median_axis(Axis(0)), arr0(7.));
+    assert!(array![[1., f64::NAN]].median_axis(Axis(1))[0].is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mode_axis() {
+    let a = array![[4, 1, 4, 2, 1, 1], [7, 7, 3, 3, 5, 5], [9, 8, 7, 6, 5, 4]];
+    assert_eq!(a.mode_axis(Axis(1)), array![1, 7, 9]);
+    // ties are resolved by the first occurrence along the axis
+    assert_eq!(a.slice(s![.., ..;-1]).mode_axis(Axis(1)), array![1, 5, 4]);
+    assert_eq!(a.mode_axis(Axis(0)), array![4, 1, 4, 2, 5, 1]);
+
+    let labels = array![["cat", "dog"], ["dog", "dog"], ["cat", "bird"]];
+    assert_eq!(labels.mode_axis(Axis(0)), array!["cat", "dog"]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn mode_axis_empty() {
+    Array2::<u8>::zeros((2, 0)).mode_axis(Axis(1));
+}
+
+#[test]
+fn rank_axis() {
+    use ndarray::TieMethod::*;
+
+    let a = array![[3., 1., 3., 2., 3.], [0., 0., 5., f64::NAN, -1.]];
+    assert_eq!(a.rank_axis(Axis(1), Average).row(0), array![4., 1., 4., 2., 4.]);
+    assert_eq!(a.rank_axis(Axis(1), Min).row(0), array![3., 1., 3., 2., 3.]);
+    assert_eq!(a.rank_axis(Axis(1), Max).row(0), array![5., 1., 5., 2., 5.]);
+    assert_eq!(a.rank_axis(Axis(1), Dense).row(0), array![3., 1., 3., 2., 3.]);
+    assert_eq!(a.rank_axis(Axis(1), Ordinal).row(0), array![3., 1., 4., 2., 5.]);
+    // NaN is ranked last
+    assert_eq!(a.rank_axis(Axis(1), Average).row(1), array![2.5, 2.5, 4., 5., 1.]);
+
+    // lanes with a non-unit stride, and along another axis
+    let b = Array::from_shape_fn((4, 3), |(i, j)| ((i * 5 + j * 2) % 4) as i32);
+    let r = b.rank_axis(Axis(0), Dense);
+    for (col, ranks) in b.columns().into_iter().zip(r.columns()) {
+        assert_eq!(ranks, col.rank_axis(Axis(0), Dense));
+    }
+    assert_eq!(b.t().rank_axis(Axis(1), Ordinal), b.rank_axis(Axis(0), Ordinal).t());
+
+    let e = Array2::<f64>::zeros((3, 0));
+    assert_eq!(e.rank_axis(Axis(1), Average).shape(), &[3, 0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn masked_mean() {
+    let a = Array::from_shape_fn((4, 6), |(i, j)| (i * 6 + j) as f64);
+    let mask = a.mapv(|x| x as usize % 3 != 1);
+    let selected: Vec<f64> = a.iter().cloned().filter(|x| *x as usize % 3 != 1).collect();
+    let expected = selected.iter().sum::<f64>() / selected.len() as f64;
+    assert_eq!(a.masked_mean(&mask), expected);
+    assert_eq!(a.t().masked_mean(&mask.t()), expected);
+    assert_eq!(a.masked_mean(&Array2::from_elem((4, 6), true)), a.mean().unwrap());
+    assert!(a.masked_mean(&Array2::from_elem((4, 6), false)).is_nan());
+    assert!(Array1::<f32>::zeros(0).masked_mean(&Array1::from_elem(0, true)).is_nan());
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn masked_mean_shape_mismatch() {
+    let a = Array2::<f64>::zeros((2, 3));
+    a.masked_mean(&Array2::from_elem((3, 2), true));
+}
+
+#[test]
+fn interp() {
+    use ndarray::{interp, interp_with, ErrorKind};
+
+    let xp = array![1., 2., 2., 4.];
+    let fp = array![0., 10., 20., 0.];
+    let x = array![0., 1., 1.5, 2., 3., 4., 5., f64::NAN];
+    let y = interp(&x, &xp, &fp).unwrap();
+    assert_eq!(y.slice(s![..7]), array![0., 0., 5., 20., 10., 0., 0.]);
+    assert!(y[7].is_nan());
+
+    // strided inputs
+    let xr = x.slice(s![..7;-1]);
+    assert_eq!(interp(&xr, &xp, &fp).unwrap(), y.slice(s![..7;-1]));
+    let xp2 = array![[0., 9.], [1., 9.], [2., 9.]];
+    assert_eq!(interp(&array![0.25, 1.5], &xp2.column(0), &array![0., 4., 8.]).unwrap(), array![1., 6.]);
+
+    let y = interp_with(&x, &xp, &fp, -1., 1.).unwrap();
+    assert_eq!(y.slice(s![..7]), array![-1., 0., 5., 20., 10., 0., 1.]);
+
+    // a single point
+    assert_eq!(interp(&array![0., 3.], &array![1.], &array![7.]).unwrap(), array![7., 7.]);
+
+    let err = |r: Result<Array1<f64>, ndarray::ShapeError>| r.unwrap_err().kind();
+    assert_eq!(err(interp(&x, &xp, &array![1., 2.])), ErrorKind::IncompatibleShape);
+    assert_eq!(err(interp(&x, &Array1::zeros(0), &Array1::zeros(0))), ErrorKind::IncompatibleShape);
+    assert_eq!(err(interp(&x, &array![1., 0.], &array![1., 2.])), ErrorKind::Unsupported);
+    assert_eq!(err(interp(&x, &array![0., f64::NAN], &array![1., 2.])), ErrorKind::Unsupported);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn gradient() {
+    // f(i, j) = i² + 3 j, sampled with spacing 0.5 along both axes
+    let f = Array::from_shape_fn((5, 4), |(i, j)| {
+        let (x, y) = (i as f64 * 0.5, j as f64 * 0.5);
+        x * x + 3. * y
+    });
+    let dx = f.gradient(Axis(0), 0.5);
+    let dy = f.gradient(Axis(1), 0.5);
+    assert_eq!(dx.shape(), f.shape());
+    // exact for a quadratic in the interior, one-sided at the ends
+    assert_eq!(dx.column(2), array![0.5, 1., 2., 3., 3.5]);
+    assert_eq!(dy, Array2::from_elem((5, 4), 3.));
+
+    assert_eq!(f.t().gradient(Axis(1), 0.5), dx.t());
+    assert_eq!(array![2., 5.].gradient(Axis(0), 1.), array![3., 3.]);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).gradient(Axis(1), 1.).shape(), &[0, 3]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn gradient_short_axis() {
+    array![[1., 2.]].gradient(Axis(0), 1.);
+}
+
+#[test]
+fn convolve() {
+    use ndarray::ConvMode::*;
+
+    let a = array![1., 2., 3., 4., 5.];
+    let v = array![1., 0., -1.];
+    assert_eq!(a.convolve(&v, Full), array![1., 2., 2., 2., 2., -4., -5.]);
+    assert_eq!(a.convolve(&v, Same), array![2., 2., 2., 2., -4.]);
+    assert_eq!(a.convolve(&v, Valid), array![2., 2., 2.]);
+    // convolution is commutative, also when the kernel is longer
+    assert_eq!(v.convolve(&a, Full), a.convolve(&v, Full));
+    assert_eq!(v.convolve(&a, Same), a.convolve(&v, Same));
+    assert_eq!(v.convolve(&a, Valid), a.convolve(&v, Valid));
+    // even kernel length, and strided inputs
+    let w = array![[1, 9], [1, 9]];
+    let b = array![3, 1, 4, 1, 5, 9];
+    assert_eq!(b.slice(s![..;-1]).convolve(&w.column(0), Same), array![9, 14, 6, 5, 5, 4]);
+    assert_eq!(b.convolve(&array![2], Valid), &b * 2);
+}
+
+#[test]
+#[should_panic]
+fn convolve_empty_kernel() {
+    array![1., 2.].convolve(&Array1::zeros(0), ConvMode::Full);
+}
+
+#[test]
+fn correlate() {
+    use ndarray::ConvMode::*;
+
+    let a = array![1., 2., 3., 4., 5.];
+    let v = array![1., 0., -1.];
+    // the kernel is not symmetric, so correlation and convolution differ in sign
+    assert_eq!(a.correlate(&v, Full), array![-1., -2., -2., -2., -2., 4., 5.]);
+    assert_eq!(a.correlate(&v, Full), -a.convolve(&v, Full));
+    assert_eq!(a.correlate(&v, Same), array![-2., -2., -2., -2., 4.]);
+    assert_eq!(a.correlate(&v, Valid), array![-2., -2., -2.]);
+    assert_eq!(a.correlate(&v.slice(s![..;-1]), Same), a.convolve(&v, Same));
+
+    // the lag of a template in a signal
+    let signal = array![0., 0., 1., 3., 2., 0., 0., 0.];
+    let template = array![1., 3., 2.];
+    let c = signal.correlate(&template, Valid);
+    let lag = c.indexed_iter().fold((0, f64::MIN), |best, (i, &x)| if x > best.1 { (i, x) } else { best }).0;
+    assert_eq!(lag, 2);
+    // a longer second input
+    assert_eq!(array![1, 2].correlate(&array![1, 2, 3], Full), array![3, 8, 5, 2]);
+    // with an even shorter length, `Same` picks the part NumPy does
+    let (short, long) = (array![1, 2], array![1, 2, 3, 4]);
+    assert_eq!(short.correlate(&long, Same), array![11, 8, 5, 2]);
+    let full = short.correlate(&long, Full);
+    assert_eq!(full, array![4, 11, 8, 5, 2]);
+    assert!(full.is_standard_layout());
+    assert_eq!(long.correlate(&short, Same), array![2, 5, 8, 11]);
+    assert_eq!(short.correlate(&long, Valid), array![11, 8, 5]);
+    assert_eq!(array![1, 2, 3, 4].correlate(&array![1, 1, 1, 1, 1, 1], Same), array![6, 10, 10, 10, 9, 7]);
+}
@@ -6,11 +6,12 @@
 )]
 #![cfg(feature = "std")]
 use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::{contract, contract1};
 use ndarray::linalg::kron;
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
 use ndarray::{Data, LinalgScalar};
-use ndarray::{Ix, Ixs};
+use ndarray::{ErrorKind, Ix, Ixs};
 use num_traits::Zero;
 
 use approx::assert_abs_diff_eq;
@@ -158,6 +159,29 @@ where
         .fold(f32::zero(), |acc, (&x, &y)| acc + x * y)
 }
 
+#[test]
+fn cross_product() {
+    let x = arr1(&[1., 0., 0.]);
+    let y = arr1(&[0., 1., 0.]);
+    let z = arr1(&[0., 0., 1.]);
+    assert_eq!(x.cross(&y), z);
+    assert_eq!(y.cross(&z), x);
+    assert_eq!(z.cross(&x), y);
+    assert_eq!(x.cross(&x), Array1::zeros(3));
+
+    let a = arr1(&[2, -3, 5, 9]);
+    let b = arr1(&[-1, 4, 7]);
+    assert_eq!(a.slice(s![..3]).cross(&b), arr1(&[-41, -19, 5]));
+    assert_eq!(b.cross(&a.slice(s![..3])), arr1(&[41, 19, -5]));
+    assert_eq!(a.slice(s![..3;-1]).cross(&b), arr1(&[-29, -37, 17]));
+}
+
+#[test]
+#[should_panic(expected = "length 3")]
+fn cross_product_wrong_length() {
+    arr1(&[1., 2.]).cross(&arr1(&[1., 2.]));
+}
+
 #[test]
 fn dot_product() {
     let a = Array::range(0., 69., 1.);
@@ -485,6 +509,29 @@ fn mat_mul_rev() {
     assert_eq!(c1, c2);
 }
 
+#[test]
+fn mat_mul_small_square() {
+    for n in 1..=5 {
+        let a = range_mat(n, n);
+        let b = range_mat(n, n).mapv(|x| x - 3.);
+        let answer = reference_mat_mul(&a, &b);
+        assert_eq!(a.dot(&b), answer);
+        assert_eq!(a.t().dot(&b.t()), reference_mat_mul(&a.t(), &b.t()));
+        let rev = b.slice(s![..;-1, ..]);
+        assert_eq!(a.dot(&rev), reference_mat_mul(&a, &rev));
+
+        let a = range_mat64(n, n);
+        let b = a.t().mapv(|x| 1. - x);
+        assert_eq!(a.dot(&b), reference_mat_mul(&a, &b));
+
+        let a = range_i32(n, n);
+        assert_eq!(a.dot(&a.t()), reference_mat_mul(&a, &a.t()));
+    }
+    let a = range_mat(3, 6);
+    let b = a.slice(s![.., ..;2]);
+    assert_eq!(b.dot(&b), reference_mat_mul(&b, &b));
+}
+
 // Check that matrix multiplication supports arrays with zero rows or columns
 #[test]
 fn mat_mut_zero_len() {
@@ -664,6 +711,43 @@ fn gemm_64_1_f() {
     approx::assert_relative_eq!(y, answer, epsilon = 1e-12, max_relative = 1e-7);
 }
 
+#[test]
+fn dot_blocked() {
+    let a = Array::from_shape_fn((37, 50), |(i, j)| ((i * 31 + j * 17) % 23) as i64 - 11);
+    let b = Array::from_shape_fn((50, 29), |(i, j)| ((i * 13 + j * 7) % 19) as i64 - 9);
+    let expected = Array::from_shape_fn((37, 29), |(i, j)| a.row(i).dot(&b.column(j)));
+    // `.dot()` uses the blocked product by default for integers
+    assert_eq!(a.dot(&b), expected);
+    assert_eq!(a.t().to_owned().t().dot(&b.t().to_owned().t()), expected);
+    for &block in &[1, 2, 7, 16, 64, 100] {
+        assert_eq!(a.dot_blocked(&b, block), expected);
+        assert_eq!(a.t().reversed_axes().dot_blocked(&b.slice(s![.., ..]), block), expected);
+    }
+    let bt = b.t().to_owned();
+    assert_eq!(a.dot_blocked(&bt.t(), 8), expected);
+
+    // floats: the result does not depend on the block size
+    let af = a.mapv(|x| x as f64 / 3.);
+    let bf = b.mapv(|x| x as f64 / 7.);
+    let c = af.dot_blocked(&bf, 1);
+    for &block in &[3, 16, 64] {
+        assert_eq!(af.dot_blocked(&bf, block), c);
+    }
+    assert!(c.all_close(&af.dot(&bf), 1e-9));
+
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.dot_blocked(&Array2::zeros((3, 4)), 2).shape(), &[0, 4]);
+    let e = Array2::<i32>::zeros((2, 0));
+    assert_eq!(e.dot_blocked(&Array2::zeros((0, 4)), 2), Array2::zeros((2, 4)));
+}
+
+#[test]
+#[should_panic]
+fn dot_blocked_shape_mismatch() {
+    let a = Array2::<i32>::zeros((2, 3));
+    a.dot_blocked(&Array2::zeros((2, 3)), 4);
+}
+
 #[test]
 fn gen_mat_mul_i32() {
     let alpha = -1;
@@ -883,3 +967,60 @@ fn kron_i64() {
     ]);
     assert_eq!(kron(&a, &b), r);
 }
+
+#[test]
+fn contract_two_operands() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i64 - 5);
+    let b = Array::from_shape_fn((4, 2), |(i, j)| (i + 2 * j) as i64);
+    assert_eq!(contract(&a, &b, "ij,jk->ik").unwrap(), a.dot(&b).into_dyn());
+    assert_eq!(contract(&a, &b, "ij,jk->ki").unwrap(), a.dot(&b).reversed_axes().into_dyn());
+    assert_eq!(contract(&b.t(), &a.t(), "kj,ji->ik").unwrap(), a.dot(&b).into_dyn());
+
+    let c = a.mapv(|x| x * 2 + 1);
+    assert_eq!(contract(&a, &c, "ij,ij->").unwrap()[[]], (&a * &c).sum());
+    assert_eq!(contract(&a, &c, "ij,ij->i").unwrap(), (&a * &c).sum_axis(Axis(1)).into_dyn());
+
+    let u = arr1(&[1, 2, 3]);
+    let v = arr1(&[4, 5]);
+    let outer = contract(&u, &v, "i,j->ij").unwrap();
+    assert_eq!(outer, arr2(&[[4, 5], [8, 10], [12, 15]]).into_dyn());
+
+    let x = Array::from_shape_fn((2, 3, 4), |(b, i, j)| (b * 12 + i * 4 + j) as i64);
+    let y = Array::from_shape_fn((2, 4, 2), |(b, j, k)| (b + j * k) as i64);
+    let batch = contract(&x, &y, "bij,bjk->bik").unwrap();
+    assert_eq!(batch.shape(), &[2, 3, 2]);
+    for b in 0..2 {
+        let expected = x.index_axis(Axis(0), b).dot(&y.index_axis(Axis(0), b));
+        assert_eq!(batch.index_axis(Axis(0), b), expected.into_dyn());
+    }
+}
+
+#[test]
+fn contract_errors() {
+    let a = Array2::<f64>::zeros((3, 4));
+    let b = Array2::<f64>::zeros((4, 2));
+    for spec in &["ij,jk", "ij,jk,kl->il", "ij,jk->ik->i", "iJ,Jk->ik", "ii,jk->k", "ij,jk->ii", "ij,jk->iz"] {
+        assert_eq!(contract(&a, &b, spec).unwrap_err().kind(), ErrorKind::Unsupported, "{}", spec);
+    }
+    for spec in &["ij,kj->ik", "i,jk->ik", "ijk,jk->ik"] {
+        assert_eq!(contract(&a, &b, spec).unwrap_err().kind(), ErrorKind::IncompatibleShape, "{}", spec);
+    }
+}
+
+#[test]
+fn contract_one_operand() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i64);
+    assert_eq!(contract1(&a, "ijk->kij").unwrap(), a.view().permuted_axes([2, 0, 1]).into_dyn());
+    assert_eq!(contract1(&a, "ijk->ijk").unwrap(), a.clone().into_dyn());
+    assert_eq!(contract1(&a, "ijk->ik").unwrap(), a.sum_axis(Axis(1)).into_dyn());
+    assert_eq!(contract1(&a, "ijk->kj").unwrap(), a.sum_axis(Axis(0)).reversed_axes().into_dyn());
+    assert_eq!(contract1(&a, "ijk->").unwrap()[[]], a.sum());
+
+    let m = arr2(&[[1., 2.], [3., 4.], [5., 6.]]);
+    assert_eq!(contract1(&m, "ij->ji").unwrap(), m.t().into_dyn());
+
+    for spec in &["ij", "ij,jk->ik", "ij->jj", "ij->k", "iJ->i"] {
+        assert_eq!(contract1(&m, spec).unwrap_err().kind(), ErrorKind::Unsupported, "{}", spec);
+    }
+    assert_eq!(contract1(&m, "ijk->ik").unwrap_err().kind(), ErrorKind::IncompatibleShape);
+}
@@ -46,6 +46,73 @@ fn test_regular_iter() {
     assert_eq!(s, a.sum());
 }
 
+#[test]
+fn test_par_try_map_inplace() {
+    let mut a = Array2::<f64>::zeros((M, N));
+    let r: Result<(), ()> = a.par_try_map_inplace(|x| {
+        *x += 1.;
+        Ok(())
+    });
+    assert_eq!(r, Ok(()));
+    assert_eq!(a.sum(), (M * N) as f64);
+
+    let mut a = Array::from_shape_fn((M, N), |(i, j)| i * N + j);
+    let r = a.par_try_map_inplace(|x| {
+        if *x % 1000 == 999 {
+            return Err(*x);
+        }
+        *x = 0;
+        Ok(())
+    });
+    let bad = r.unwrap_err();
+    assert_eq!(bad % 1000, 999);
+    assert_eq!(a[[bad / N, bad % N]], bad);
+}
+
+#[test]
+fn test_par_nan_to_num_inplace() {
+    let mut a = Array::from_shape_fn((M, N), |(i, j)| match (i + j) % 4 {
+        0 => f64::NAN,
+        1 => f64::INFINITY,
+        2 => f64::NEG_INFINITY,
+        _ => 1.,
+    });
+    let expected = a.nan_to_num(0., 2., -2.);
+    a.par_nan_to_num_inplace(0., 2., -2.);
+    assert_eq!(a, expected);
+    assert!(a.iter().all(|x| x.is_finite()));
+}
+
+#[test]
+fn test_par_map_indexed() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let b = a.par_map_indexed(|(i, j), &x| x * i as f64 + j as f64);
+    assert_eq!(b, a.map_indexed(|(i, j), &x| x * i as f64 + j as f64));
+
+    let v = a.slice(s![..;-3, ..;2]);
+    assert_eq!(v.par_map_indexed(|(i, _), &x| x - i as f64), v.map_indexed(|(i, _), &x| x - i as f64));
+}
+
+#[test]
+fn test_par_map_collect_vec() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (N * i + j) as f64);
+    let expected = a.iter().map(|&x| x + 1.).collect::<Vec<_>>();
+    assert_eq!(a.par_map_collect_vec(|&x| x + 1.), expected);
+
+    let f = a.t();
+    assert_eq!(f.par_map_collect_vec(|&x| x as usize), f.iter().map(|&x| x as usize).collect::<Vec<_>>());
+
+    let v = a.slice(s![..;-3, 1..;2]);
+    assert_eq!(v.par_map_collect_vec(|x| x.to_string()), v.iter().map(|x| x.to_string()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_par_norm_l2() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i as f64 - j as f64) / 100.);
+    let norm = a.par_norm_l2();
+    assert!((norm - a.norm_l2()).abs() <= 1e-9 * norm);
+}
+
 #[test]
 fn test_regular_iter_collect() {
     let mut a = Array2::<f64>::zeros((M, N));
@@ -86,3 +153,38 @@ fn test_axis_chunks_iter_mut() {
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_from_shape_iter() {
+    let a = Array::par_from_shape_iter((M, N), (0..M * N).into_par_iter().map(|x| x as f64));
+    assert_eq!(a, Array::from_shape_fn((M, N), |(i, j)| (N * i + j) as f64));
+
+    let f = Array::par_from_shape_iter((M, N).f(), (0..M * N).into_par_iter());
+    assert!(f.t().is_standard_layout());
+    assert_eq!(f, Array::from_shape_fn((M, N), |(i, j)| N * i + j));
+
+    let s = Array::par_from_shape_iter((2, 3, 4).f(), (0..24).into_par_iter().map(|x| x.to_string()));
+    assert_eq!(s, Array::from_shape_fn((2, 3, 4), |(i, j, k)| (12 * i + 4 * j + k).to_string()));
+
+    let e = Array::par_from_shape_iter((0, N), Vec::<u8>::new());
+    assert_eq!(e.shape(), &[0, N]);
+}
+
+#[test]
+#[should_panic]
+fn test_par_from_shape_iter_wrong_len() {
+    Array::par_from_shape_iter((3, 4), (0..11).into_par_iter());
+}
+
+#[test]
+fn test_par_from_shape_fn() {
+    let f = |(i, j): (usize, usize)| (i * j) as f64 - j as f64;
+    assert_eq!(Array::par_from_shape_fn((M, N), f), Array::from_shape_fn((M, N), f));
+
+    let a = Array::par_from_shape_fn((M, N).f(), f);
+    assert!(a.t().is_standard_layout());
+    assert_eq!(a, Array::from_shape_fn((M, N), f));
+
+    let b = Array::par_from_shape_fn((3, 0, 2), |(i, j, k)| i + j + k);
+    assert_eq!(b.shape(), &[3, 0, 2]);
+}
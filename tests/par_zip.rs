@@ -71,6 +71,34 @@ fn test_zip_index_4() {
     }
 }
 
+#[test]
+fn test_zip_with_min_len() {
+    let mut a = Array2::zeros((M, N));
+    let b = Array::from_shape_fn((M, N), |(i, j)| i * N + j);
+
+    Zip::from(&mut a).and(&b).par_for_each_with_min_len(N, |x, &y| *x = y + 1);
+    assert_eq!(a, &b + 1);
+
+    let mut c = Array2::<usize>::zeros((3, 4));
+    Zip::indexed(&mut c).par_for_each_with_min_len(100, |(i, j), x| *x = i + j);
+    assert_eq!(c, Array::from_shape_fn((3, 4), |(i, j)| i + j));
+}
+
+#[test]
+fn test_zip_and_mut() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let mut b = Array2::<f64>::zeros((M, N));
+    Zip::from(&a).and_mut(&mut b).par_for_each(|&a, b| *b = a * a);
+    assert_eq!(b, a.mapv(|x| x * x));
+}
+
+#[test]
+#[should_panic]
+fn test_zip_with_min_len_zero() {
+    let mut a = Array2::<f64>::zeros((10, 10));
+    Zip::from(&mut a).par_for_each_with_min_len(0, |x| *x = 1.);
+}
+
 #[test]
 #[cfg(feature = "approx")]
 fn test_zip_collect() {
@@ -140,3 +168,33 @@ fn test_zip_assign_into() {
 
     assert_abs_diff_eq!(a, &b + &c, epsilon = 1e-6);
 }
+
+#[test]
+fn test_zip_par_fold_while() {
+    use ndarray::FoldWhile;
+
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as u64);
+
+    // without Done, it is the same as par_fold
+    let sum = Zip::from(&a).par_fold_while(|| 0, |acc, &x| FoldWhile::Continue(acc + x), |x, y| x + y);
+    assert_eq!(sum, a.sum());
+
+    // each task stops at its first element above the limit and contributes that element
+    let limit = 1000;
+    let result = Zip::from(&a).par_fold_while(
+        || (0, 0),
+        |(n, max), &x| {
+            if x > limit {
+                FoldWhile::Done((n + 1, max.max(x)))
+            } else {
+                FoldWhile::Continue((n, max.max(x)))
+            }
+        },
+        |x, y| (x.0 + y.0, x.1.max(y.1)),
+    );
+    assert!(result.0 >= 1);
+    assert!(result.1 > limit);
+
+    let e = Array2::<u64>::zeros((0, N));
+    assert_eq!(Zip::from(&e).par_fold_while(|| 7, |acc, _| FoldWhile::Done(acc), |x, y| x.max(y)), 7);
+}
@@ -151,6 +151,46 @@ fn to_shape_copy_stride() {
 }
 
 
+#[test]
+fn flatten() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let expected = Array::from_iter(a.iter().cloned());
+
+    let flat = a.flatten();
+    assert!(flat.is_view());
+    assert_eq!(flat, expected);
+
+    let at = a.t();
+    let flat = at.flatten();
+    assert!(flat.is_owned());
+    assert_eq!(flat, Array::from_iter(at.iter().cloned()));
+
+    let sliced = a.slice(s![.., 1.., ..;-1]);
+    let flat = sliced.flatten();
+    assert!(flat.is_owned());
+    assert_eq!(flat.len(), 16);
+    assert_eq!(flat[0], 13);
+
+    let e = Array2::<i32>::zeros((3, 0));
+    assert_eq!(e.flatten().shape(), &[0]);
+}
+
+#[test]
+fn into_flat() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let expected = Array::from_iter(a.iter().cloned());
+
+    let ptr = a.as_ptr();
+    let flat = a.clone().into_flat();
+    assert_eq!(flat, expected);
+    let flat = a.into_flat();
+    assert_eq!(flat.as_ptr(), ptr);
+
+    let b = expected.clone().into_shape((4, 6)).unwrap();
+    assert_eq!(b.view().reversed_axes().into_flat(), Array::from_iter(b.t().iter().cloned()));
+    assert_eq!(b.slice_move(s![1..3, ..]).into_flat(), expected.slice(s![6..18]));
+}
+
 #[test]
 fn to_shape_zero_len() {
     let v = array![[1, 2, 3, 4], [5, 6, 7, 8]];
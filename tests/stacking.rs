@@ -1,4 +1,4 @@
-use ndarray::{arr2, arr3, aview1, aview2, concatenate, stack, Array2, Axis, ErrorKind, Ix1};
+use ndarray::{arr2, arr3, aview1, aview2, concatenate, stack, stack_owned, Array, Array2, Axis, ErrorKind, Ix1, Ix2};
 
 #[test]
 fn concatenating() {
@@ -51,3 +51,29 @@ fn stacking() {
     let res: Result<Array2<f64>, _> = ndarray::stack::<_, Ix1>(Axis(0), &[]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
 }
+
+#[test]
+fn stacking_owned() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let b = arr2(&[[5, 6], [7, 8]]);
+    let c = stack_owned(Axis(1), vec![a.clone(), b.clone()]).unwrap();
+    assert_eq!(c, stack![Axis(1), a, b]);
+
+    let c = stack_owned(Axis(2), vec![a.t().to_owned(), b.clone()]).unwrap();
+    assert_eq!(c, stack![Axis(2), a.t(), b]);
+
+    let strings = vec![Array::from(vec![String::from("a"), String::from("b")]),
+                       Array::from(vec![String::from("c"), String::from("d")])];
+    let s = stack_owned(Axis(0), strings).unwrap();
+    assert_eq!(s, arr2(&[["a", "b"], ["c", "d"]]).map(|x| x.to_string()));
+
+    let d = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let res = stack_owned(Axis(0), vec![a.clone(), d]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+
+    let res = stack_owned(Axis(3), vec![a.clone(), b]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::OutOfBounds);
+
+    let res = stack_owned::<i32, Ix2>(Axis(0), vec![]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+}
@@ -232,3 +232,59 @@ fn test_window_neg_stride() {
         answer.iter()
     );
 }
+
+#[test]
+fn test_rolling_apply() {
+    let a = Array::from_iter(0..6);
+    assert_eq!(a.rolling_apply(1, |w| w[0] * 2), arr1(&[0, 2, 4, 6, 8, 10]));
+    assert_eq!(a.rolling_apply(3, |w| w.sum()), arr1(&[3, 6, 9, 12]));
+    assert_eq!(a.rolling_apply(6, |w| w.len()), arr1(&[6]));
+
+    let v = a.slice(s![..;-2]);
+    assert_eq!(v.rolling_apply(2, |w| w.to_vec()), arr1(&[vec![5, 3], vec![3, 1]]));
+}
+
+#[test]
+#[should_panic]
+fn rolling_apply_zero_size() {
+    let a = Array::from_iter(0..6);
+    a.rolling_apply(0, |w| w.sum());
+}
+
+#[test]
+#[should_panic]
+fn rolling_apply_oversized() {
+    let a = Array::from_iter(0..6);
+    a.rolling_apply(7, |w| w.sum());
+}
+
+#[test]
+fn windows_with_stride() {
+    let a = Array::from_iter(10..37).into_shape((3, 3, 3)).unwrap();
+    let w = a.windows_with_stride((2, 2, 2), (1, 1, 1));
+    itertools::assert_equal(w, a.windows((2, 2, 2)));
+
+    let b = Array::from_shape_fn((5, 6), |(i, j)| 10 * i + j);
+    let w = b.windows_with_stride((2, 3), (2, 3));
+    itertools::assert_equal(w, b.exact_chunks((2, 3)));
+
+    // overlapping and strided windows, and strides on a non-standard layout
+    let t = b.t();
+    let w = t.windows_with_stride((3, 1), (2, 3));
+    itertools::assert_equal(w, vec![
+        arr2(&[[0], [1], [2]]),
+        arr2(&[[30], [31], [32]]),
+        arr2(&[[2], [3], [4]]),
+        arr2(&[[32], [33], [34]]),
+    ]);
+
+    // windows that don't fit
+    assert_eq!(b.windows_with_stride((6, 1), (1, 1)).into_iter().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn windows_with_stride_zero_stride() {
+    let a = Array::<f32, _>::zeros((4, 4));
+    a.windows_with_stride((2, 2), (0, 1));
+}